@@ -0,0 +1,236 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, BufRead};
+use std::num::IntErrorKind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// Position of the elf in the input, starting at 0.
+    pub index: usize,
+    pub calories: u64,
+    /// Amount of food items the elf carries.
+    pub items: usize,
+    pub min: u64,
+    pub max: u64,
+}
+
+impl Elf {
+    pub fn mean(&self) -> f64 {
+        self.calories as f64 / self.items as f64
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseErrorKind {
+    Io(io::Error),
+    InvalidCalories(String),
+    Overflow,
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    /// Line of the input where the error happened, starting at 1.
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::Io(err) => write!(f, "line {}: {}", self.line, err),
+            ParseErrorKind::InvalidCalories(text) => {
+                write!(f, "line {}: invalid calories {:?}", self.line, text)
+            }
+            ParseErrorKind::Overflow => write!(f, "line {}: calories overflow", self.line),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Splits a reader in lines accepting `\n`, `\r\n` and lone `\r` line endings.
+struct Lines<R> {
+    reader: R,
+    pending: VecDeque<String>,
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(line) = self.pending.pop_front() {
+            return Some(Ok(line));
+        }
+
+        let mut buf = vec![];
+        match self.reader.read_until(b'\n', &mut buf) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(err) => return Some(Err(err)),
+        }
+
+        if buf.ends_with(b"\n") {
+            buf.pop();
+        }
+        if buf.ends_with(b"\r") {
+            buf.pop();
+        }
+
+        let buf = match String::from_utf8(buf) {
+            Ok(buf) => buf,
+            Err(err) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, err))),
+        };
+
+        self.pending.extend(buf.split('\r').map(String::from));
+        self.pending.pop_front().map(Ok)
+    }
+}
+
+/// Reads elves one group of lines at a time. Groups are separated by lines that are empty or only
+/// contain whitespace, and surrounding whitespace on each line is ignored.
+pub struct ElfGroups<R> {
+    lines: Lines<R>,
+    line: usize,
+    index: usize,
+}
+
+impl<R: BufRead> ElfGroups<R> {
+    pub fn new(reader: R) -> Self {
+        ElfGroups {
+            lines: Lines {
+                reader,
+                pending: VecDeque::new(),
+            },
+            line: 0,
+            index: 0,
+        }
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            line: self.line,
+            kind,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for ElfGroups<R> {
+    type Item = Result<Elf, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut calories: u64 = 0;
+        let mut items = 0;
+        let mut min = u64::MAX;
+        let mut max = u64::MIN;
+
+        while let Some(line) = self.lines.next() {
+            self.line += 1;
+
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(self.error(ParseErrorKind::Io(err)))),
+            };
+
+            let line = line.trim();
+
+            if line.is_empty() {
+                if items > 0 {
+                    break;
+                }
+
+                continue;
+            }
+
+            let cal = match line.parse::<u64>() {
+                Ok(cal) => cal,
+                Err(err) if *err.kind() == IntErrorKind::PosOverflow => {
+                    return Some(Err(self.error(ParseErrorKind::Overflow)));
+                }
+                Err(_) => {
+                    let kind = ParseErrorKind::InvalidCalories(line.to_string());
+                    return Some(Err(self.error(kind)));
+                }
+            };
+
+            calories = match calories.checked_add(cal) {
+                Some(calories) => calories,
+                None => return Some(Err(self.error(ParseErrorKind::Overflow))),
+            };
+            items += 1;
+            min = min.min(cal);
+            max = max.max(cal);
+        }
+
+        if items == 0 {
+            return None;
+        }
+
+        let elf = Elf {
+            index: self.index,
+            calories,
+            items,
+            min,
+            max,
+        };
+        self.index += 1;
+
+        Some(Ok(elf))
+    }
+}
+
+/// Elves carrying one item each, with `calories` in the order of the input.
+#[cfg(test)]
+pub(crate) fn with_calories(calories: &[u64]) -> Vec<Elf> {
+    calories
+        .iter()
+        .enumerate()
+        .map(|(index, calories)| Elf {
+            index,
+            calories: *calories,
+            items: 1,
+            min: *calories,
+            max: *calories,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn totals(input: &str) -> Vec<u64> {
+        ElfGroups::new(input.as_bytes())
+            .map(|elf| elf.unwrap().calories)
+            .collect()
+    }
+
+    fn error(input: &str) -> ParseError {
+        ElfGroups::new(input.as_bytes())
+            .find_map(Result::err)
+            .unwrap()
+    }
+
+    #[test]
+    fn any_newline_style() {
+        assert_eq!(totals("1\n2\n\n3\n"), vec![3, 3]);
+        assert_eq!(totals("1\r\n2\r\n\r\n3\r\n"), vec![3, 3]);
+        assert_eq!(totals("1\r2\r\r3\r"), vec![3, 3]);
+    }
+
+    #[test]
+    fn whitespace_only_lines_separate_groups() {
+        assert_eq!(totals("1 \n 2\n  \t\n3\n\n\n\n4"), vec![3, 3, 4]);
+    }
+
+    #[test]
+    fn reports_the_offending_line() {
+        let err = error("1\n2\n\n3\nfour\n");
+        assert_eq!(err.line, 5);
+        assert!(matches!(err.kind, ParseErrorKind::InvalidCalories(text) if text == "four"));
+
+        let err = error("1\n\n18446744073709551615\n1\n");
+        assert_eq!(err.line, 4);
+        assert!(matches!(err.kind, ParseErrorKind::Overflow));
+    }
+}
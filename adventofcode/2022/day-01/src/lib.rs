@@ -0,0 +1,70 @@
+pub mod elves;
+pub mod stats;
+pub mod top_k;
+
+use aoc::{PartResult, Solution};
+
+use elves::{Elf, ElfGroups};
+use top_k::{top_k, total};
+
+pub struct Day01;
+
+/// The `k` elves carrying the most calories, streamed into `top_k` up to the first error.
+fn top_calories(input: &str, k: usize) -> Result<Vec<Elf>, Box<dyn std::error::Error>> {
+    let mut error = None;
+    let elves =
+        ElfGroups::new(input.as_bytes()).map_while(|elf| elf.map_err(|err| error = Some(err)).ok());
+
+    let top = top_k(elves, k);
+
+    match error {
+        Some(err) => Err(err.into()),
+        None => Ok(top),
+    }
+}
+
+impl Solution for Day01 {
+    fn part_1(&self, input: &str) -> PartResult {
+        let top = top_calories(input, 1)?;
+
+        Ok(top.first().ok_or("no elves in the input")?.calories.into())
+    }
+
+    fn part_2(&self, input: &str) -> Option<PartResult> {
+        Some(top_calories(input, 3).and_then(|top| Ok(total(&top)?.into())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::test_examples!(2022, 1, Day01);
+
+    #[test]
+    fn first_error_is_returned() {
+        let err = top_calories("1000\n\nx\n\ny\n", 3).unwrap_err();
+
+        assert_eq!(err.to_string(), "line 3: invalid calories \"x\"");
+    }
+
+    #[test]
+    fn totals_past_i64() {
+        let input = "10000000000000000000\n\n5000000000000000000\n\n1\n";
+
+        assert_eq!(
+            Day01.part_1(input).unwrap().to_string(),
+            "10000000000000000000"
+        );
+        assert_eq!(
+            Day01.part_2(input).unwrap().unwrap().to_string(),
+            "15000000000000000001"
+        );
+
+        let err = Day01
+            .part_2("18446744073709551615\n\n1\n")
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "total calories overflow");
+    }
+}
@@ -1,24 +1,81 @@
+use std::error::Error;
+use std::process;
+
+use day_01::elves::{Elf, ElfGroups};
+use day_01::stats::{Format, Report};
+use day_01::top_k::{top_k, total};
+use day_01::Day01;
+
+const USAGE: &str = "Usage: day-01 [--input <path>|-] [--example <name>] [--top K | stats [--format table|csv|json] [--buckets N]]";
+
+enum Command {
+    Answer,
+    Top(usize),
+    Stats { format: Format, buckets: usize },
+}
+
+fn parse_args(args: &[String]) -> Result<Command, Box<dyn Error>> {
+    match args {
+        [] => Ok(Command::Answer),
+        [flag, k] if flag == "--top" => Ok(Command::Top(k.parse()?)),
+        [cmd, flags @ ..] if cmd == "stats" => {
+            let mut format = Format::Table;
+            let mut buckets = 10;
+
+            for flag in flags.chunks(2) {
+                match flag {
+                    [flag, value] if flag == "--format" => format = value.parse()?,
+                    [flag, value] if flag == "--buckets" => buckets = value.parse()?,
+                    _ => return Err(USAGE.into()),
+                }
+            }
+
+            Ok(Command::Stats { format, buckets })
+        }
+        _ => Err(USAGE.into()),
+    }
+}
+
 fn main() {
-    let calories = include_str!("../input.txt");
-
-    let calories = calories
-        .split("\n\n")
-        .fold((0, 0, 0), |(top1, top2, top3), elf_calories| {
-            let sum = elf_calories
-                .lines()
-                .map(|cal| cal.parse::<i32>().unwrap())
-                .sum::<i32>();
-
-            match sum {
-                sum if sum > top1 => (sum, top1, top2),
-                sum if sum > top2 => (top1, sum, top2),
-                sum if sum > top3 => (top1, top2, sum),
-                _ => (top1, top2, top3),
+    let (calories, args) = aoc::input::from_args(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let calories = calories.as_str();
+    let command = parse_args(&args).unwrap();
+
+    let elves = ElfGroups::new(calories.as_bytes()).map(|elf| {
+        elf.unwrap_or_else(|err| {
+            eprintln!("{err}");
+            process::exit(1);
+        })
+    });
+
+    match command {
+        Command::Answer => aoc::print_answers(2022, 1, &Day01, calories),
+        Command::Top(k) => {
+            let top = top_k(elves, k);
+
+            for Elf {
+                index, calories, ..
+            } in &top
+            {
+                println!("Elf {} {}", index + 1, calories);
             }
-        });
 
-    let (top1, top2, top3) = calories;
+            match total(&top) {
+                Ok(total) => println!("Total {total}"),
+                Err(err) => {
+                    eprintln!("{err}");
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Stats { format, buckets } => {
+            let report = Report::new(&elves.collect::<Vec<_>>(), buckets);
 
-    println!("Result 1 {:?}", top1);
-    println!("Result 2 {:?}", top1 + top2 + top3);
+            match format {
+                Format::Table => print!("{report}"),
+                Format::Csv => print!("{}", report.to_csv()),
+                Format::Json => println!("{}", serde_json::to_string_pretty(&report).unwrap()),
+            }
+        }
+    }
 }
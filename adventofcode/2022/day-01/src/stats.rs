@@ -0,0 +1,308 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::elves::Elf;
+
+const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+
+#[derive(Debug, Serialize)]
+pub struct ElfStats {
+    /// Position of the elf in the input, starting at 1.
+    elf: usize,
+    /// 1 is the elf carrying the most calories, elves with the same total share a rank.
+    rank: usize,
+    items: usize,
+    total: u64,
+    min: u64,
+    max: u64,
+    mean: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Percentile {
+    percentile: u8,
+    total: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Bucket {
+    /// Inclusive lower bound.
+    from: u64,
+    /// Exclusive upper bound, except for the last bucket.
+    to: u64,
+    elves: usize,
+}
+
+/// Distribution of the elf totals.
+#[derive(Debug, Serialize)]
+pub struct Distribution {
+    elves: usize,
+    min: u64,
+    max: u64,
+    mean: f64,
+    median: f64,
+    percentiles: Vec<Percentile>,
+    histogram: Vec<Bucket>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    elves: Vec<ElfStats>,
+    distribution: Distribution,
+}
+
+impl Report {
+    pub fn new(elves: &[Elf], buckets: usize) -> Self {
+        let mut totals = elves.iter().map(|elf| elf.calories).collect::<Vec<_>>();
+        totals.sort_unstable();
+
+        let elves = elves
+            .iter()
+            .map(|elf| ElfStats {
+                elf: elf.index + 1,
+                rank: totals.len() - totals.partition_point(|total| *total <= elf.calories) + 1,
+                items: elf.items,
+                total: elf.calories,
+                min: elf.min,
+                max: elf.max,
+                mean: elf.mean(),
+            })
+            .collect();
+
+        Report {
+            elves,
+            distribution: Distribution::new(&totals, buckets),
+        }
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("elf,rank,items,total,min,max,mean\n");
+
+        for elf in &self.elves {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{:.2}\n",
+                elf.elf, elf.rank, elf.items, elf.total, elf.min, elf.max, elf.mean
+            ));
+        }
+
+        csv
+    }
+}
+
+impl Distribution {
+    /// `totals` must be sorted.
+    fn new(totals: &[u64], buckets: usize) -> Self {
+        let (min, max) = match (totals.first(), totals.last()) {
+            (Some(min), Some(max)) => (*min, *max),
+            _ => (0, 0),
+        };
+
+        let mean = if totals.is_empty() {
+            0.0
+        } else {
+            totals.iter().map(|total| *total as f64).sum::<f64>() / totals.len() as f64
+        };
+
+        let median = match totals.len() {
+            0 => 0.0,
+            len if len % 2 == 0 => (totals[len / 2 - 1] as f64 + totals[len / 2] as f64) / 2.0,
+            len => totals[len / 2] as f64,
+        };
+
+        // Nearest-rank method
+        let percentiles = PERCENTILES
+            .iter()
+            .filter(|_| !totals.is_empty())
+            .map(|percentile| {
+                let rank = (*percentile as usize * totals.len()).div_ceil(100);
+
+                Percentile {
+                    percentile: *percentile,
+                    total: totals[rank.max(1) - 1],
+                }
+            })
+            .collect();
+
+        Distribution {
+            elves: totals.len(),
+            min,
+            max,
+            mean,
+            median,
+            percentiles,
+            histogram: histogram(totals, min, max, buckets),
+        }
+    }
+}
+
+/// Splits `min..=max` into `buckets` buckets of the same width and counts the totals in each.
+fn histogram(totals: &[u64], min: u64, max: u64, buckets: usize) -> Vec<Bucket> {
+    if totals.is_empty() || buckets == 0 {
+        return vec![];
+    }
+
+    // The last bucket holds `max` too, so `max - min` is what has to be covered
+    let width = (max - min).div_ceil(buckets as u64).max(1);
+
+    let mut histogram = (0..buckets as u64)
+        .map(|bucket| Bucket {
+            from: min + bucket * width,
+            to: min + (bucket + 1) * width,
+            elves: 0,
+        })
+        .collect::<Vec<_>>();
+
+    for total in totals {
+        let bucket = ((total - min) / width) as usize;
+        histogram[bucket.min(buckets - 1)].elves += 1;
+    }
+
+    histogram
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>5} | {:>5} | {:>5} | {:>7} | {:>6} | {:>6} | {:>8}",
+            "elf", "rank", "items", "total", "min", "max", "mean"
+        )?;
+        writeln!(f, "{:-<60}", "")?;
+
+        for elf in &self.elves {
+            writeln!(
+                f,
+                "{:>5} | {:>5} | {:>5} | {:>7} | {:>6} | {:>6} | {:>8.2}",
+                elf.elf, elf.rank, elf.items, elf.total, elf.min, elf.max, elf.mean
+            )?;
+        }
+
+        let distribution = &self.distribution;
+
+        writeln!(f)?;
+        writeln!(f, "Elves:  {}", distribution.elves)?;
+        writeln!(f, "Min:    {}", distribution.min)?;
+        writeln!(f, "Max:    {}", distribution.max)?;
+        writeln!(f, "Mean:   {:.2}", distribution.mean)?;
+        writeln!(f, "Median: {:.1}", distribution.median)?;
+
+        for Percentile { percentile, total } in &distribution.percentiles {
+            writeln!(f, "p{:<5} {}", percentile, total)?;
+        }
+
+        writeln!(f)?;
+        for Bucket { from, to, elves } in &distribution.histogram {
+            writeln!(
+                f,
+                "{:>7} - {:>7} | {:>4} {}",
+                from,
+                to,
+                elves,
+                "#".repeat(*elves)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format {s:?}").into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elves::with_calories;
+
+    fn counts(histogram: &[Bucket]) -> Vec<usize> {
+        histogram.iter().map(|bucket| bucket.elves).collect()
+    }
+
+    #[test]
+    fn distribution_of_the_example() {
+        let report = Report::new(&with_calories(&[6000, 4000, 11000, 24000, 10000]), 2);
+        let distribution = &report.distribution;
+
+        assert_eq!((distribution.min, distribution.max), (4000, 24000));
+        assert_eq!(distribution.mean, 11000.0);
+        assert_eq!(distribution.median, 10000.0);
+        assert_eq!(
+            distribution
+                .percentiles
+                .iter()
+                .map(|percentile| percentile.total)
+                .collect::<Vec<_>>(),
+            vec![4000, 6000, 10000, 11000, 24000, 24000]
+        );
+        assert_eq!(counts(&distribution.histogram), vec![4, 1]);
+    }
+
+    #[test]
+    fn median_of_an_even_count() {
+        let report = Report::new(&with_calories(&[1, 2, 3, 10]), 1);
+
+        assert_eq!(report.distribution.median, 2.5);
+    }
+
+    #[test]
+    fn ties_share_a_rank() {
+        let report = Report::new(&with_calories(&[5, 7, 5, 3]), 1);
+        let ranks = report.elves.iter().map(|elf| elf.rank).collect::<Vec<_>>();
+
+        assert_eq!(ranks, vec![2, 1, 2, 4]);
+    }
+
+    #[test]
+    fn every_bucket_is_used() {
+        let report = Report::new(&with_calories(&(0..=10).collect::<Vec<_>>()), 10);
+        let histogram = &report.distribution.histogram;
+
+        assert_eq!(counts(histogram), vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 2]);
+        assert_eq!((histogram[9].from, histogram[9].to), (9, 10));
+    }
+
+    #[test]
+    fn no_elves() {
+        let report = Report::new(&[], 10);
+        let distribution = &report.distribution;
+
+        assert_eq!(distribution.elves, 0);
+        assert_eq!(distribution.median, 0.0);
+        assert!(distribution.percentiles.is_empty());
+        assert!(distribution.histogram.is_empty());
+    }
+
+    #[test]
+    fn a_single_elf() {
+        let report = Report::new(&with_calories(&[42]), 3);
+        let distribution = &report.distribution;
+
+        assert_eq!(report.elves[0].rank, 1);
+        assert_eq!(distribution.median, 42.0);
+        assert!(distribution
+            .percentiles
+            .iter()
+            .all(|percentile| percentile.total == 42));
+        assert_eq!(counts(&distribution.histogram), vec![1, 0, 0]);
+    }
+}
@@ -0,0 +1,118 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::error::Error;
+
+use crate::elves::Elf;
+
+/// An elf in the heap, ordered by how it ranks: more calories is better, then a lower index.
+struct Ranked(Elf);
+
+impl Ranked {
+    fn key(&self) -> (u64, Reverse<usize>) {
+        (self.0.calories, Reverse(self.0.index))
+    }
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Ranked {}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+/// The `k` elves carrying the most calories, most calories first.
+///
+/// Only `k` elves are kept in memory at any time (a min-heap whose root is the weakest of the
+/// current top). On ties the elf that comes first in the input wins.
+pub fn top_k<I: IntoIterator<Item = Elf>>(elves: I, k: usize) -> Vec<Elf> {
+    if k == 0 {
+        return vec![];
+    }
+
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+
+    for elf in elves.into_iter().map(Ranked) {
+        if heap.len() < k {
+            heap.push(Reverse(elf));
+            continue;
+        }
+
+        let Reverse(weakest) = heap.peek().unwrap();
+        if elf > *weakest {
+            heap.pop();
+            heap.push(Reverse(elf));
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(Ranked(elf))| elf)
+        .collect()
+}
+
+/// Calories carried by all of `elves` together, an error when they don't fit in a `u64`.
+pub fn total(elves: &[Elf]) -> Result<u64, Box<dyn Error>> {
+    elves
+        .iter()
+        .try_fold(0u64, |total, elf| total.checked_add(elf.calories))
+        .ok_or_else(|| "total calories overflow".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elves::with_calories;
+
+    fn indexes(elves: &[Elf]) -> Vec<usize> {
+        elves.iter().map(|elf| elf.index).collect()
+    }
+
+    #[test]
+    fn picks_the_largest() {
+        let top = top_k(with_calories(&[6000, 4000, 11000, 24000, 10000]), 3);
+
+        assert_eq!(indexes(&top), vec![3, 2, 4]);
+        assert_eq!(total(&top).unwrap(), 45000);
+    }
+
+    #[test]
+    fn ties_keep_the_first_elf() {
+        let top = top_k(with_calories(&[5, 7, 5, 7, 5]), 3);
+
+        assert_eq!(indexes(&top), vec![1, 3, 0]);
+    }
+
+    #[test]
+    fn fewer_elves_than_k() {
+        let top = top_k(with_calories(&[1, 3, 2]), 10);
+
+        assert_eq!(indexes(&top), vec![1, 2, 0]);
+        assert!(top_k(with_calories(&[]), 3).is_empty());
+        assert!(top_k(with_calories(&[1, 2]), 0).is_empty());
+    }
+
+    #[test]
+    fn total_overflow() {
+        let top = top_k(with_calories(&[u64::MAX - 10, 10, 1]), 2);
+        assert_eq!(total(&top).unwrap(), u64::MAX);
+
+        let top = top_k(with_calories(&[u64::MAX - 10, 10, 11]), 2);
+        assert_eq!(
+            total(&top).unwrap_err().to_string(),
+            "total calories overflow"
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::rules::{Game, Rules, Scoring};
+use crate::{ExpectedResult, RPS};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScoringConfig {
+    pub shapes: HashMap<RPS, i32>,
+    pub outcomes: HashMap<ExpectedResult, i32>,
+}
+
+/// How to read a strategy guide and score it, see `config.toml` for the 2022 interpretation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    /// First column, the hand the oponent plays.
+    pub opponent: HashMap<String, RPS>,
+    /// Second column read as the hand to play (part 1).
+    pub me: HashMap<String, RPS>,
+    /// Second column read as the result the round has to end with (part 2).
+    pub expected: HashMap<String, ExpectedResult>,
+    pub scoring: ScoringConfig,
+}
+
+impl Config {
+    pub fn aoc_2022() -> Self {
+        Config::parse(include_str!("../config.toml")).unwrap()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        Config::parse(&fs::read_to_string(path)?)
+    }
+
+    fn parse(s: &str) -> Result<Self, Box<dyn Error>> {
+        let config: Config = toml::from_str(s)?;
+
+        for hand in RPS::ALL {
+            if !config.scoring.shapes.contains_key(&hand) {
+                return Err(format!("Missing score for shape {hand:?} in [scoring.shapes]").into());
+            }
+        }
+
+        for result in ExpectedResult::ALL {
+            if !config.scoring.outcomes.contains_key(&result) {
+                // Named like in `config.toml`, `Tie` is read from `Draw`
+                let key = result.outcome();
+                return Err(
+                    format!("Missing score for outcome {key:?} in [scoring.outcomes]").into(),
+                );
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn game(&self) -> Game {
+        let shapes = &self.scoring.shapes;
+        let outcomes = &self.scoring.outcomes;
+
+        Game {
+            rules: Rules::rock_paper_scissors(),
+            scoring: Scoring {
+                shapes: RPS::ALL.iter().map(|hand| shapes[hand]).collect(),
+                lose: outcomes[&ExpectedResult::Lose],
+                draw: outcomes[&ExpectedResult::Tie],
+                win: outcomes[&ExpectedResult::Win],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const CONFIG: &str = include_str!("../config.toml");
+
+    #[test]
+    fn loads_a_file() {
+        let path = env::temp_dir().join(format!("day-02-config-{}.toml", std::process::id()));
+        fs::write(&path, CONFIG.replace("Win = 6", "Win = 10")).unwrap();
+
+        let config = Config::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(config.opponent["B"], RPS::Paper);
+        assert_eq!(config.me["Z"], RPS::Scissors);
+        assert_eq!(config.expected["Y"], ExpectedResult::Tie);
+
+        let game = config.game();
+        assert_eq!(game.scoring.shapes, vec![1, 2, 3]);
+        assert_eq!(
+            (game.scoring.lose, game.scoring.draw, game.scoring.win),
+            (0, 3, 10)
+        );
+
+        assert!(Config::load("no-such-config.toml").is_err());
+    }
+
+    #[test]
+    fn missing_shape() {
+        let err = Config::parse(&CONFIG.replace("Paper = 2\n", "")).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Missing score for shape Paper in [scoring.shapes]"
+        );
+    }
+
+    #[test]
+    fn missing_outcome() {
+        let err = Config::parse(&CONFIG.replace("Draw = 3\n", "")).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Missing score for outcome Draw in [scoring.outcomes]"
+        );
+    }
+}
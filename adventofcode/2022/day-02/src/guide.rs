@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::RPS;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsePointError {
+    /// Line of the guide, starting at 1.
+    pub line: usize,
+    /// Column of the token in characters, starting at 1.
+    pub column: usize,
+    /// The token we found, `None` when the line ended before it.
+    pub token: Option<String>,
+    /// Tokens that would have been valid, empty when the line should have ended.
+    pub expected: Vec<String>,
+}
+
+impl Display for ParsePointError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+
+        match &self.token {
+            Some(token) => write!(f, "unexpected {token:?}, ")?,
+            None => write!(f, "missing token, ")?,
+        }
+
+        if self.expected.is_empty() {
+            write!(f, "expected end of line")
+        } else {
+            write!(f, "expected one of {}", self.expected.join(", "))
+        }
+    }
+}
+
+impl Error for ParsePointError {}
+
+/// Whitespace separated tokens of a line, with their column.
+fn tokens(line: &str) -> Vec<(usize, &str)> {
+    let mut tokens = vec![];
+    let mut start = None;
+
+    for (column, (offset, c)) in line.char_indices().enumerate() {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some((column + 1, offset)),
+            (Some((token_column, token_offset)), true) => {
+                tokens.push((token_column, &line[token_offset..offset]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((column, offset)) = start {
+        tokens.push((column, &line[offset..]));
+    }
+
+    tokens
+}
+
+fn letters<T>(column: &HashMap<String, T>) -> Vec<String> {
+    let mut letters = column.keys().cloned().collect::<Vec<_>>();
+    letters.sort();
+
+    letters
+}
+
+/// Reads one line of the guide, reporting every problem in it.
+fn parse_line<T: Clone>(
+    index: usize,
+    line: &str,
+    first: &HashMap<String, RPS>,
+    second: &HashMap<String, T>,
+) -> Result<(RPS, T), Vec<ParsePointError>> {
+    let tokens = tokens(line);
+    let mut errors = vec![];
+
+    let mut decode = |position: usize, letters: Vec<String>| match tokens.get(position) {
+        Some((column, token)) => {
+            if !letters.iter().any(|letter| letter == token) {
+                errors.push(ParsePointError {
+                    line: index + 1,
+                    column: *column,
+                    token: Some(token.to_string()),
+                    expected: letters,
+                });
+            }
+        }
+        None => errors.push(ParsePointError {
+            line: index + 1,
+            column: line.chars().count() + 1,
+            token: None,
+            expected: letters,
+        }),
+    };
+
+    decode(0, letters(first));
+    decode(1, letters(second));
+
+    if let Some((column, token)) = tokens.get(2) {
+        errors.push(ParsePointError {
+            line: index + 1,
+            column: *column,
+            token: Some(token.to_string()),
+            expected: vec![],
+        });
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok((first[tokens[0].1], second[tokens[1].1].clone()))
+}
+
+fn lines<'a, T: Clone + 'a>(
+    input: &'a str,
+    first: &'a HashMap<String, RPS>,
+    second: &'a HashMap<String, T>,
+) -> impl Iterator<Item = Result<(RPS, T), Vec<ParsePointError>>> + 'a {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(move |(index, line)| parse_line(index, line, first, second))
+}
+
+/// Reads the guide decoding the first column with `first` and the second one with `second`,
+/// stopping at the first error.
+pub fn parse<T: Clone>(
+    input: &str,
+    first: &HashMap<String, RPS>,
+    second: &HashMap<String, T>,
+) -> Result<Vec<(RPS, T)>, ParsePointError> {
+    lines(input, first, second)
+        .map(|line| line.map_err(|mut errors| errors.remove(0)))
+        .collect()
+}
+
+/// Same as `parse` but goes through the whole guide and returns every error found.
+pub fn parse_all<T: Clone>(
+    input: &str,
+    first: &HashMap<String, RPS>,
+    second: &HashMap<String, T>,
+) -> Result<Vec<(RPS, T)>, Vec<ParsePointError>> {
+    let mut rounds = vec![];
+    let mut errors = vec![];
+
+    for line in lines(input, first, second) {
+        match line {
+            Ok(round) => rounds.push(round),
+            Err(line_errors) => errors.extend(line_errors),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(rounds)
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn stops_at_the_first_error() {
+        let config = Config::aoc_2022();
+        let err = parse("A Y\nB Q\nD X\n", &config.opponent, &config.me).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: unexpected \"Q\", expected one of X, Y, Z"
+        );
+    }
+
+    #[test]
+    fn collects_every_error() {
+        let config = Config::aoc_2022();
+        let errors = parse_all("A Y\n\n D  Q\nC\nB X Z\n", &config.opponent, &config.me)
+            .unwrap_err()
+            .into_iter()
+            .map(|err| (err.line, err.column, err.token))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (3, 2, Some("D".to_string())),
+                (3, 5, Some("Q".to_string())),
+                (4, 2, None),
+                (5, 5, Some("Z".to_string())),
+            ]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+pub mod config;
+pub mod guide;
+pub mod rules;
+pub mod solver;
+pub mod tournament;
+
+use aoc::{PartResult, Solution};
+use serde::Deserialize;
+
+use config::Config;
+use rules::{Game, Outcome};
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Deserialize)]
+pub enum RPS {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl RPS {
+    pub const ALL: [RPS; 3] = [Self::Rock, Self::Paper, Self::Scissors];
+
+    /// Position of the weapon in `Rules::rock_paper_scissors`.
+    pub fn index(&self) -> usize {
+        match self {
+            Self::Rock => 0,
+            Self::Paper => 1,
+            Self::Scissors => 2,
+        }
+    }
+
+    pub fn from_index(index: usize) -> Self {
+        match index {
+            0 => Self::Rock,
+            1 => Self::Paper,
+            2 => Self::Scissors,
+            _ => panic!("Unexpected weapon {index}"),
+        }
+    }
+
+    pub fn play(game: &Game, me: &Self, oponent: &Self) -> i32 {
+        game.score(me.index(), oponent.index())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Deserialize)]
+pub enum ExpectedResult {
+    Lose,
+    #[serde(alias = "Draw")]
+    Tie,
+    Win,
+}
+
+impl ExpectedResult {
+    pub const ALL: [ExpectedResult; 3] = [Self::Lose, Self::Tie, Self::Win];
+
+    pub fn outcome(&self) -> Outcome {
+        match self {
+            Self::Lose => Outcome::Lose,
+            Self::Tie => Outcome::Draw,
+            Self::Win => Outcome::Win,
+        }
+    }
+
+    pub fn get_hand(&self, game: &Game, oponent: RPS) -> RPS {
+        let hands = game.rules.hands_for(oponent.index(), self.outcome());
+
+        RPS::from_index(hands[0])
+    }
+}
+
+/// Scores the guide with the letters and scoring of `config`.
+pub struct Day02 {
+    config: Config,
+    game: Game,
+}
+
+impl Day02 {
+    pub fn new(config: Config) -> Self {
+        let game = config.game();
+
+        Day02 { config, game }
+    }
+}
+
+impl Default for Day02 {
+    fn default() -> Self {
+        Day02::new(Config::aoc_2022())
+    }
+}
+
+impl Solution for Day02 {
+    fn part_1(&self, input: &str) -> PartResult {
+        let Config { opponent, me, .. } = &self.config;
+
+        let score = guide::parse(input, opponent, me)?
+            .iter()
+            .map(|(oponent, me)| RPS::play(&self.game, me, oponent))
+            .sum::<i32>();
+
+        Ok(score.into())
+    }
+
+    fn part_2(&self, input: &str) -> Option<PartResult> {
+        let Config {
+            opponent, expected, ..
+        } = &self.config;
+
+        let score = guide::parse(input, opponent, expected).map(|rounds| {
+            rounds
+                .iter()
+                .map(|(oponent, expected)| {
+                    let me = expected.get_hand(&self.game, *oponent);
+
+                    RPS::play(&self.game, &me, oponent)
+                })
+                .sum::<i32>()
+        });
+
+        Some(score.map(Into::into).map_err(Into::into))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::test_examples!(2022, 2, Day02::default());
+
+    /// The hand-written table `RPS::play` used before the rules engine.
+    fn play_table(me: RPS, oponent: RPS) -> i32 {
+        let points = match me {
+            RPS::Rock => 1,
+            RPS::Paper => 2,
+            RPS::Scissors => 3,
+        };
+
+        match (me, oponent) {
+            (RPS::Rock, RPS::Scissors) => 6 + points,
+            (RPS::Paper, RPS::Rock) => 6 + points,
+            (RPS::Scissors, RPS::Paper) => 6 + points,
+
+            (RPS::Paper, RPS::Scissors) => points,
+            (RPS::Scissors, RPS::Rock) => points,
+            (RPS::Rock, RPS::Paper) => points,
+
+            _ => 3 + points,
+        }
+    }
+
+    /// The hand-written table `ExpectedResult::get_hand` used before the rules engine.
+    fn hand_table(expected: &ExpectedResult, oponent: RPS) -> RPS {
+        match (expected, oponent) {
+            (ExpectedResult::Win, RPS::Rock) => RPS::Paper,
+            (ExpectedResult::Win, RPS::Paper) => RPS::Scissors,
+            (ExpectedResult::Win, RPS::Scissors) => RPS::Rock,
+
+            (ExpectedResult::Lose, RPS::Rock) => RPS::Scissors,
+            (ExpectedResult::Lose, RPS::Paper) => RPS::Rock,
+            (ExpectedResult::Lose, RPS::Scissors) => RPS::Paper,
+
+            (ExpectedResult::Tie, a) => a,
+        }
+    }
+
+    #[test]
+    fn derived_play_matches_table() {
+        let game = Game::aoc_2022();
+
+        for me in RPS::ALL {
+            for oponent in RPS::ALL {
+                assert_eq!(RPS::play(&game, &me, &oponent), play_table(me, oponent));
+            }
+        }
+    }
+
+    #[test]
+    fn derived_hand_matches_table() {
+        let game = Game::aoc_2022();
+
+        for expected in &ExpectedResult::ALL {
+            for oponent in RPS::ALL {
+                assert_eq!(
+                    expected.get_hand(&game, oponent),
+                    hand_table(expected, oponent)
+                );
+            }
+        }
+    }
+}
@@ -1,133 +1,180 @@
-use std::str::FromStr;
-
-#[derive(Debug, PartialEq, Clone, Copy)]
-enum RPS {
-    Rock,
-    Paper,
-    Scissors,
+use std::error::Error;
+use std::fmt::Debug;
+use std::process;
+
+use day_02::config::Config;
+use day_02::guide::{self, ParsePointError};
+use day_02::solver::{self, Candidate};
+use day_02::tournament::{self, Entrant, Fixed, FrequencyCounter, Random, StrategyGuide};
+use day_02::{Day02, RPS};
+
+const USAGE: &str = "Usage: day-02 [--input <path>|-] [--example <name>] [--config <path>] \
+    [check | solve [--target <score>] | tournament [--rounds <n>] [--seed <seed>]]";
+
+enum Command {
+    Answer,
+    /// Report every error in the strategy guide.
+    Check,
+    /// Find the second column meanings that give the max score, or exactly `target`.
+    Solve {
+        target: Option<i32>,
+    },
+    /// Pit the player strategies against each other.
+    Tournament {
+        rounds: usize,
+        seed: u64,
+    },
 }
 
-impl RPS {
-    pub fn points(&self) -> i32 {
-        match self {
-            Self::Rock => 1,
-            Self::Paper => 2,
-            Self::Scissors => 3,
+fn parse_args(args: &[String]) -> Result<(Config, Command), Box<dyn Error>> {
+    let mut config = Config::aoc_2022();
+    let mut command = Command::Answer;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), &mut command) {
+            ("--config", _) => config = Config::load(args.next().ok_or(USAGE)?)?,
+            ("check", Command::Answer) => command = Command::Check,
+            ("solve", Command::Answer) => command = Command::Solve { target: None },
+            ("--target", Command::Solve { target }) => {
+                *target = Some(args.next().ok_or(USAGE)?.parse()?)
+            }
+            ("tournament", Command::Answer) => {
+                command = Command::Tournament {
+                    rounds: 1000,
+                    seed: 2022,
+                }
+            }
+            ("--rounds", Command::Tournament { rounds, .. }) => {
+                *rounds = args.next().ok_or(USAGE)?.parse()?;
+                if *rounds == 0 {
+                    return Err("A tournament needs at least one round".into());
+                }
+            }
+            ("--seed", Command::Tournament { seed, .. }) => {
+                *seed = args.next().ok_or(USAGE)?.parse()?
+            }
+            _ => return Err(USAGE.into()),
         }
     }
 
-    pub fn play(me: &Self, oponent: &Self) -> i32 {
-        match (me, oponent) {
-            // Win
-            (Self::Rock, Self::Scissors) => 6 + me.points(),
-            (Self::Paper, Self::Rock) => 6 + me.points(),
-            (Self::Scissors, Self::Paper) => 6 + me.points(),
-
-            // Lose
-            (Self::Paper, Self::Scissors) => me.points(),
-            (Self::Scissors, Self::Rock) => me.points(),
-            (Self::Rock, Self::Paper) => me.points(),
+    Ok((config, command))
+}
 
-            // Tie
-            _ => 3 + me.points(),
-        }
-    }
+/// Rounds of the guide, or the first error in it.
+fn rounds_or_exit<T>(rounds: Result<Vec<T>, ParsePointError>) -> Vec<T> {
+    rounds.unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    })
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct ParsePointError;
+/// Reads the guide with both interpretations of the second column and prints every error.
+fn check(input: &str, config: &Config) {
+    let mut errors = vec![];
 
-impl FromStr for RPS {
-    type Err = ParsePointError;
+    if let Err(err) = guide::parse_all(input, &config.opponent, &config.me) {
+        errors.extend(err);
+    }
+    if let Err(err) = guide::parse_all(input, &config.opponent, &config.expected) {
+        errors.extend(err);
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            // Oponent
-            "A" => Ok(Self::Rock),
-            "B" => Ok(Self::Paper),
-            "C" => Ok(Self::Scissors),
+    errors.sort_by_key(|err| (err.line, err.column));
+    errors.dedup_by_key(|err| (err.line, err.column));
 
-            // Self
-            "X" => Ok(Self::Rock),
-            "Y" => Ok(Self::Paper),
-            "Z" => Ok(Self::Scissors),
+    if errors.is_empty() {
+        println!("No errors");
+        return;
+    }
 
-            _ => Err(ParsePointError),
-        }
+    for err in &errors {
+        eprintln!("{err}");
     }
-}
 
-#[derive(Debug, PartialEq)]
-enum ExpectedResult {
-    Lose,
-    Tie,
-    Win,
+    process::exit(1);
 }
 
-impl ExpectedResult {
-    fn get_hand(&self, oponent: RPS) -> RPS {
-        match (self, oponent) {
-            (Self::Win, RPS::Rock) => RPS::Paper,
-            (Self::Win, RPS::Paper) => RPS::Scissors,
-            (Self::Win, RPS::Scissors) => RPS::Rock,
+fn print_candidates<T: Debug>(title: &str, candidates: &[Candidate<T>], target: Option<i32>) {
+    println!("{title}");
 
-            (Self::Lose, RPS::Rock) => RPS::Scissors,
-            (Self::Lose, RPS::Paper) => RPS::Rock,
-            (Self::Lose, RPS::Scissors) => RPS::Paper,
+    match target {
+        None => {
+            let max = candidates[0].score;
 
-            (Self::Tie, a) => a,
+            for candidate in candidates {
+                let label = if candidate.score == max { " (max)" } else { "" };
+                println!("  {candidate}{label}");
+            }
         }
-    }
-}
-
-impl FromStr for ExpectedResult {
-    type Err = ParsePointError;
+        Some(target) => {
+            let matches = solver::scoring(candidates, target);
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "X" => Ok(Self::Lose),
-            "Y" => Ok(Self::Tie),
-            "Z" => Ok(Self::Win),
+            if matches.is_empty() {
+                println!("  No mapping scores {target}");
+            }
 
-            _ => Err(ParsePointError),
+            for candidate in matches {
+                println!("  {candidate}");
+            }
         }
     }
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
+fn solve(input: &str, config: &Config, target: Option<i32>) {
+    let mut letters = config.me.keys().cloned().collect::<Vec<_>>();
+    letters.sort();
+
+    // The second column is left as is, the solver gives it a meaning
+    let second = letters
+        .iter()
+        .map(|letter| (letter.clone(), letter.clone()))
+        .collect();
+    let rounds = rounds_or_exit(guide::parse(input, &config.opponent, &second));
+
+    let game = config.game();
+    let (hands, results) = solver::solve_hands(&game, &letters, &rounds)
+        .and_then(|hands| Ok((hands, solver::solve_results(&game, &letters, &rounds)?)))
+        .unwrap_or_else(|err| {
+            eprintln!("{err}");
+            process::exit(1);
+        });
+
+    print_candidates("Second column as hand", &hands, target);
+    print_candidates("Second column as result", &results, target);
+}
 
-    let result1: i32 = input
-        .lines()
-        .map(
-            |line| match line.split_whitespace().collect::<Vec<&str>>()[..] {
-                [oponent, me] => {
-                    let oponent = oponent.parse::<RPS>().unwrap();
-                    let me = me.parse::<RPS>().unwrap();
+fn tournament(input: &str, config: &Config, rounds: usize, seed: u64) {
+    let guide = rounds_or_exit(guide::parse(input, &config.opponent, &config.expected));
+
+    let mut entrants = vec![
+        Entrant::new("always rock", || Fixed::new(vec![RPS::Rock])),
+        Entrant::new("cycle", || Fixed::new(RPS::ALL.to_vec())),
+        Entrant::new("random", move || Random::new(seed)),
+        Entrant::new("frequency counter", || FrequencyCounter),
+    ];
+
+    if guide.is_empty() {
+        eprintln!("The strategy guide is empty, it doesn't take part");
+    } else {
+        entrants.push(Entrant::new("strategy guide", move || {
+            StrategyGuide::new(guide.clone())
+        }));
+    }
 
-                    RPS::play(&me, &oponent)
-                }
-                _ => panic!("Unexpected line {:?}", line),
-            },
-        )
-        .sum();
-
-    let result2: i32 = input
-        .lines()
-        .map(
-            |line| match line.split_whitespace().collect::<Vec<&str>>()[..] {
-                [oponent, expected] => {
-                    let oponent = oponent.parse::<RPS>().unwrap();
-                    let expected = expected.parse::<ExpectedResult>().unwrap();
-                    let me = expected.get_hand(oponent);
-
-                    RPS::play(&me, &oponent)
-                }
-                _ => panic!("Unexpected line {:?}", line),
-            },
-        )
-        .sum();
+    let standings = tournament::run(&config.game(), &entrants, rounds);
+    print!("{}", tournament::report(&standings));
+}
 
-    println!("Part 1 {}", result1);
-    println!("Part 2 {}", result2);
+fn main() {
+    let (input, args) = aoc::input::from_args(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let input = input.as_str();
+    let (config, command) = parse_args(&args).unwrap();
+
+    match command {
+        Command::Answer => aoc::print_answers(2022, 2, &Day02::new(config), input),
+        Command::Check => check(input, &config),
+        Command::Solve { target } => solve(input, &config, target),
+        Command::Tournament { rounds, seed } => tournament(input, &config, rounds, seed),
+    }
 }
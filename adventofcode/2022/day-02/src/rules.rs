@@ -0,0 +1,229 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RulesError {
+    /// A pair references a weapon that does not exist.
+    UnknownWeapon(usize),
+    /// A weapon can't beat itself.
+    BeatsItself(usize),
+    /// Both weapons of the pair beat each other.
+    Contradiction(usize, usize),
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownWeapon(weapon) => write!(f, "unknown weapon {weapon}"),
+            Self::BeatsItself(weapon) => write!(f, "weapon {weapon} beats itself"),
+            Self::Contradiction(a, b) => write!(f, "weapons {a} and {b} beat each other"),
+        }
+    }
+}
+
+impl Error for RulesError {}
+
+/// The beats-relation of a game, weapons are identified by their position in `weapons`.
+/// Any pair of different weapons that is not in the relation is a draw.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    weapons: Vec<String>,
+    /// `beats[a][b]` is true when `a` beats `b`.
+    beats: Vec<Vec<bool>>,
+}
+
+impl Rules {
+    /// Builds the rules from `(winner, loser)` pairs.
+    pub fn new(weapons: &[&str], pairs: &[(usize, usize)]) -> Result<Self, RulesError> {
+        let n = weapons.len();
+        let mut beats = vec![vec![false; n]; n];
+
+        for &(winner, loser) in pairs {
+            if winner >= n {
+                return Err(RulesError::UnknownWeapon(winner));
+            }
+            if loser >= n {
+                return Err(RulesError::UnknownWeapon(loser));
+            }
+            if winner == loser {
+                return Err(RulesError::BeatsItself(winner));
+            }
+            if beats[loser][winner] {
+                return Err(RulesError::Contradiction(winner, loser));
+            }
+
+            beats[winner][loser] = true;
+        }
+
+        Ok(Rules {
+            weapons: weapons.iter().map(|weapon| weapon.to_string()).collect(),
+            beats,
+        })
+    }
+
+    /// Balanced tournament for an odd amount of weapons: each weapon beats the one before it,
+    /// the one three positions before it and so on (wrapping around), so every weapon beats and
+    /// loses against exactly half of the others.
+    pub fn cyclic(weapons: &[&str]) -> Self {
+        let n = weapons.len();
+        assert!(n % 2 == 1, "cyclic rules need an odd amount of weapons");
+
+        let pairs = (0..n)
+            .flat_map(|winner| {
+                (1..n)
+                    .step_by(2)
+                    .map(move |k| (winner, (winner + n - k) % n))
+            })
+            .collect::<Vec<_>>();
+
+        Rules::new(weapons, &pairs).unwrap()
+    }
+
+    /// Rock, Paper, Scissors.
+    pub fn rock_paper_scissors() -> Self {
+        Rules::cyclic(&["Rock", "Paper", "Scissors"])
+    }
+
+    /// Rock, Paper, Scissors, Lizard, Spock. Ordered so that the cyclic rules give the usual
+    /// relation (Spock smashes scissors, lizard poisons Spock, ...).
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Rules::cyclic(&["Rock", "Paper", "Scissors", "Spock", "Lizard"])
+    }
+
+    pub fn outcome(&self, me: usize, opponent: usize) -> Outcome {
+        if self.beats[me][opponent] {
+            Outcome::Win
+        } else if self.beats[opponent][me] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// Every weapon that gives `outcome` against `opponent`.
+    pub fn hands_for(&self, opponent: usize, outcome: Outcome) -> Vec<usize> {
+        (0..self.weapons.len())
+            .filter(|me| self.outcome(*me, opponent) == outcome)
+            .collect()
+    }
+}
+
+/// Points for the weapon played plus points for the outcome of the round.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scoring {
+    /// Points per weapon, in the same order as the rules weapons.
+    pub shapes: Vec<i32>,
+    pub lose: i32,
+    pub draw: i32,
+    pub win: i32,
+}
+
+impl Scoring {
+    /// The 2022 day 2 scoring: 1/2/3 for Rock/Paper/Scissors and 0/3/6 for lose/draw/win.
+    pub fn aoc_2022() -> Self {
+        Scoring {
+            shapes: vec![1, 2, 3],
+            lose: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+
+    pub fn outcome(&self, outcome: Outcome) -> i32 {
+        match outcome {
+            Outcome::Lose => self.lose,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+}
+
+/// Rules together with the way rounds are scored.
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub rules: Rules,
+    pub scoring: Scoring,
+}
+
+impl Game {
+    pub fn aoc_2022() -> Self {
+        Game {
+            rules: Rules::rock_paper_scissors(),
+            scoring: Scoring::aoc_2022(),
+        }
+    }
+
+    pub fn score(&self, me: usize, opponent: usize) -> i32 {
+        let outcome = self.rules.outcome(me, opponent);
+
+        self.scoring.shapes[me] + self.scoring.outcome(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cyclic_rules_are_balanced() {
+        for n in [3, 5, 7, 9] {
+            let weapons = vec!["w"; n];
+            let rules = Rules::cyclic(&weapons);
+
+            for weapon in 0..n {
+                assert_eq!(rules.hands_for(weapon, Outcome::Win).len(), n / 2);
+                assert_eq!(rules.hands_for(weapon, Outcome::Lose).len(), n / 2);
+                assert_eq!(rules.hands_for(weapon, Outcome::Draw), vec![weapon]);
+            }
+        }
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let [rock, paper, scissors, spock, lizard] = [0, 1, 2, 3, 4];
+
+        let wins = [
+            (scissors, paper),
+            (paper, rock),
+            (rock, lizard),
+            (lizard, spock),
+            (spock, scissors),
+            (scissors, lizard),
+            (lizard, paper),
+            (paper, spock),
+            (spock, rock),
+            (rock, scissors),
+        ];
+
+        for (winner, loser) in wins {
+            assert_eq!(rules.outcome(winner, loser), Outcome::Win);
+            assert_eq!(rules.outcome(loser, winner), Outcome::Lose);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_relations() {
+        let weapons = ["a", "b"];
+
+        assert_eq!(
+            Rules::new(&weapons, &[(0, 2)]).unwrap_err(),
+            RulesError::UnknownWeapon(2)
+        );
+        assert_eq!(
+            Rules::new(&weapons, &[(1, 1)]).unwrap_err(),
+            RulesError::BeatsItself(1)
+        );
+        assert_eq!(
+            Rules::new(&weapons, &[(0, 1), (1, 0)]).unwrap_err(),
+            RulesError::Contradiction(1, 0)
+        );
+    }
+}
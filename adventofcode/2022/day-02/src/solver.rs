@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+
+use crate::rules::Game;
+use crate::{ExpectedResult, RPS};
+
+/// A meaning for each letter of the second column and the score the guide gets with it.
+#[derive(Debug)]
+pub struct Candidate<T> {
+    pub mapping: BTreeMap<String, T>,
+    pub score: i32,
+}
+
+impl<T: Debug> Display for Candidate<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mapping = self
+            .mapping
+            .iter()
+            .map(|(letter, value)| format!("{letter}={value:?}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        write!(f, "{mapping} {}", self.score)
+    }
+}
+
+/// Every ordering of `items`.
+fn permutations<T: Copy>(items: &[T]) -> Vec<Vec<T>> {
+    if items.len() <= 1 {
+        return vec![items.to_vec()];
+    }
+
+    (0..items.len())
+        .flat_map(|index| {
+            let mut rest = items.to_vec();
+            let item = rest.remove(index);
+
+            permutations(&rest).into_iter().map(move |mut permutation| {
+                permutation.insert(0, item);
+                permutation
+            })
+        })
+        .collect()
+}
+
+/// Scores every one-to-one mapping of `letters` to `values`, best score first.
+fn candidates<T, F>(
+    letters: &[String],
+    values: &[T],
+    score: F,
+) -> Result<Vec<Candidate<T>>, Box<dyn Error>>
+where
+    T: Copy + Debug,
+    F: Fn(&BTreeMap<String, T>) -> i32,
+{
+    if letters.len() != values.len() {
+        return Err(format!(
+            "The second column has {} letters ({}), it needs one for each of {values:?}",
+            letters.len(),
+            letters.join(", ")
+        )
+        .into());
+    }
+
+    let mut candidates = permutations(values)
+        .into_iter()
+        .map(|permutation| {
+            let mapping = letters.iter().cloned().zip(permutation).collect();
+            let score = score(&mapping);
+
+            Candidate { mapping, score }
+        })
+        .collect::<Vec<_>>();
+
+    candidates.sort_by_key(|candidate| -candidate.score);
+
+    Ok(candidates)
+}
+
+/// The candidates whose guide scores exactly `target`.
+pub fn scoring<T>(candidates: &[Candidate<T>], target: i32) -> Vec<&Candidate<T>> {
+    candidates
+        .iter()
+        .filter(|candidate| candidate.score == target)
+        .collect()
+}
+
+/// Second column read as the hand to play (part 1).
+pub fn solve_hands(
+    game: &Game,
+    letters: &[String],
+    rounds: &[(RPS, String)],
+) -> Result<Vec<Candidate<RPS>>, Box<dyn Error>> {
+    candidates(letters, &RPS::ALL, |mapping| {
+        rounds
+            .iter()
+            .map(|(oponent, letter)| RPS::play(game, &mapping[letter], oponent))
+            .sum()
+    })
+}
+
+/// Second column read as the result the round has to end with (part 2).
+pub fn solve_results(
+    game: &Game,
+    letters: &[String],
+    rounds: &[(RPS, String)],
+) -> Result<Vec<Candidate<ExpectedResult>>, Box<dyn Error>> {
+    candidates(letters, &ExpectedResult::ALL, |mapping| {
+        rounds
+            .iter()
+            .map(|(oponent, letter)| {
+                let me = mapping[letter].get_hand(game, *oponent);
+
+                RPS::play(game, &me, oponent)
+            })
+            .sum()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters(letters: &[&str]) -> Vec<String> {
+        letters.iter().map(|letter| letter.to_string()).collect()
+    }
+
+    /// The example guide with its second column left as letters.
+    fn example() -> Vec<(RPS, String)> {
+        vec![
+            (RPS::Rock, "Y".to_string()),
+            (RPS::Paper, "X".to_string()),
+            (RPS::Scissors, "Z".to_string()),
+        ]
+    }
+
+    #[test]
+    fn best_mapping_of_the_example() {
+        let game = Game::aoc_2022();
+        let letters = letters(&["X", "Y", "Z"]);
+
+        let hands = solve_hands(&game, &letters, &example()).unwrap();
+        assert_eq!(hands.len(), 6);
+        assert_eq!(hands[0].to_string(), "X=Scissors Y=Paper Z=Rock 24");
+        assert!(hands.iter().all(|candidate| candidate.score <= 24));
+
+        let results = solve_results(&game, &letters, &example()).unwrap();
+        assert_eq!(results[0].to_string(), "X=Win Y=Lose Z=Tie 18");
+    }
+
+    #[test]
+    fn target_scores() {
+        let game = Game::aoc_2022();
+        let letters = letters(&["X", "Y", "Z"]);
+
+        // The meanings of the puzzle are among the mappings scoring its answers
+        let hands = solve_hands(&game, &letters, &example()).unwrap();
+        let matches = scoring(&hands, 15);
+        assert_eq!(matches.len(), 4);
+        assert!(matches
+            .iter()
+            .any(|candidate| candidate.to_string() == "X=Rock Y=Paper Z=Scissors 15"));
+
+        let results = solve_results(&game, &letters, &example()).unwrap();
+        let matches = scoring(&results, 12);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].to_string(), "X=Lose Y=Tie Z=Win 12");
+
+        assert!(scoring(&hands, 16).is_empty());
+        assert!(scoring(&results, 0).is_empty());
+    }
+
+    #[test]
+    fn one_letter_for_each_value() {
+        let game = Game::aoc_2022();
+        let rounds = &example()[..2];
+
+        let err = solve_hands(&game, &letters(&["X", "Y"]), rounds).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The second column has 2 letters (X, Y), it needs one for each of [Rock, Paper, Scissors]"
+        );
+        assert!(solve_results(&game, &letters(&["W", "X", "Y", "Z"]), rounds).is_err());
+    }
+}
@@ -0,0 +1,326 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::rules::{Game, Outcome};
+use crate::{ExpectedResult, RPS};
+
+/// A round from the point of view of a player: `(my hand, oponent hand)`.
+pub type Round = (RPS, RPS);
+
+pub trait Player {
+    /// Hand for the next round of `game`, `history` holds every previous round of the match.
+    fn play(&mut self, game: &Game, history: &[Round]) -> RPS;
+}
+
+/// Plays the same sequence of hands over and over.
+pub struct Fixed {
+    script: Vec<RPS>,
+}
+
+impl Fixed {
+    pub fn new(script: Vec<RPS>) -> Self {
+        assert!(!script.is_empty(), "empty script");
+
+        Fixed { script }
+    }
+}
+
+impl Player for Fixed {
+    fn play(&mut self, _: &Game, history: &[Round]) -> RPS {
+        self.script[history.len() % self.script.len()]
+    }
+}
+
+/// SplitMix64, good enough to pick hands and fully reproducible from the seed.
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+
+        z ^ (z >> 31)
+    }
+}
+
+/// Picks a hand uniformly at random, the same seed always gives the same hands.
+pub struct Random {
+    rng: Rng,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random {
+            rng: Rng { state: seed },
+        }
+    }
+}
+
+impl Player for Random {
+    fn play(&mut self, _: &Game, _: &[Round]) -> RPS {
+        RPS::ALL[(self.rng.next() % 3) as usize]
+    }
+}
+
+/// Plays the hand that beats whatever the oponent played the most so far.
+pub struct FrequencyCounter;
+
+impl Player for FrequencyCounter {
+    fn play(&mut self, game: &Game, history: &[Round]) -> RPS {
+        let mut counts = [0; 3];
+        for (_, oponent) in history {
+            counts[oponent.index()] += 1;
+        }
+
+        // On ties the first hand in `RPS::ALL` is the one we expect
+        let expected = RPS::ALL
+            .into_iter()
+            .rev()
+            .max_by_key(|hand| counts[hand.index()])
+            .unwrap();
+
+        ExpectedResult::Win.get_hand(game, expected)
+    }
+}
+
+/// Trusts the strategy guide: expects the oponent to play the first column and picks the hand
+/// that ends the round as the second column says, going back to the start when it runs out.
+pub struct StrategyGuide {
+    guide: Vec<(RPS, ExpectedResult)>,
+}
+
+impl StrategyGuide {
+    pub fn new(guide: Vec<(RPS, ExpectedResult)>) -> Self {
+        assert!(!guide.is_empty(), "empty strategy guide");
+
+        StrategyGuide { guide }
+    }
+}
+
+impl Player for StrategyGuide {
+    fn play(&mut self, game: &Game, history: &[Round]) -> RPS {
+        let (oponent, expected) = self.guide[history.len() % self.guide.len()];
+
+        expected.get_hand(game, oponent)
+    }
+}
+
+/// A strategy taking part in the tournament. Each match starts from a fresh player.
+pub struct Entrant {
+    pub name: String,
+    player: Box<dyn Fn() -> Box<dyn Player>>,
+}
+
+impl Entrant {
+    pub fn new<P, F>(name: &str, player: F) -> Self
+    where
+        P: Player + 'static,
+        F: Fn() -> P + 'static,
+    {
+        Entrant {
+            name: name.to_string(),
+            player: Box::new(move || Box::new(player())),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Standing {
+    pub name: String,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: i32,
+    /// Amount of rounds per round score.
+    pub round_scores: BTreeMap<i32, usize>,
+    /// Total score of each match played.
+    pub match_scores: Vec<i32>,
+}
+
+impl Standing {
+    pub fn rounds(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    /// 0 before any round is played.
+    pub fn win_rate(&self) -> f64 {
+        self.per_round(self.wins as f64)
+    }
+
+    /// 0 before any round is played.
+    pub fn mean_score(&self) -> f64 {
+        self.per_round(self.score as f64)
+    }
+
+    fn per_round(&self, total: f64) -> f64 {
+        match self.rounds() {
+            0 => 0.0,
+            rounds => total / rounds as f64,
+        }
+    }
+
+    fn record(&mut self, game: &Game, (me, oponent): Round) -> i32 {
+        let score = RPS::play(game, &me, &oponent);
+
+        match game.rules.outcome(me.index(), oponent.index()) {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Lose => self.losses += 1,
+        }
+
+        self.score += score;
+        *self.round_scores.entry(score).or_default() += 1;
+
+        score
+    }
+}
+
+/// Plays `rounds` rounds of `game` between every pair of entrants.
+pub fn run(game: &Game, entrants: &[Entrant], rounds: usize) -> Vec<Standing> {
+    let mut standings = entrants
+        .iter()
+        .map(|entrant| Standing {
+            name: entrant.name.clone(),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    for a in 0..entrants.len() {
+        for b in a + 1..entrants.len() {
+            let mut player_a = (entrants[a].player)();
+            let mut player_b = (entrants[b].player)();
+
+            let mut history_a = Vec::with_capacity(rounds);
+            let mut history_b = Vec::with_capacity(rounds);
+            let mut score_a = 0;
+            let mut score_b = 0;
+
+            for _ in 0..rounds {
+                let hand_a = player_a.play(game, &history_a);
+                let hand_b = player_b.play(game, &history_b);
+
+                history_a.push((hand_a, hand_b));
+                history_b.push((hand_b, hand_a));
+
+                score_a += standings[a].record(game, (hand_a, hand_b));
+                score_b += standings[b].record(game, (hand_b, hand_a));
+            }
+
+            standings[a].match_scores.push(score_a);
+            standings[b].match_scores.push(score_b);
+        }
+    }
+
+    standings
+}
+
+pub fn report(standings: &[Standing]) -> String {
+    let mut report = String::new();
+
+    writeln!(
+        report,
+        "{:<18} | {:>6} | {:>6} | {:>6} | {:>8} | {:>10} | {:>9} | {:>9}",
+        "strategy", "wins", "draws", "losses", "win rate", "mean score", "min match", "max match"
+    )
+    .unwrap();
+    writeln!(report, "{:-<97}", "").unwrap();
+
+    for standing in standings {
+        writeln!(
+            report,
+            "{:<18} | {:>6} | {:>6} | {:>6} | {:>7.1}% | {:>10.2} | {:>9} | {:>9}",
+            standing.name,
+            standing.wins,
+            standing.draws,
+            standing.losses,
+            standing.win_rate() * 100.0,
+            standing.mean_score(),
+            standing.match_scores.iter().min().unwrap_or(&0),
+            standing.match_scores.iter().max().unwrap_or(&0),
+        )
+        .unwrap();
+    }
+
+    writeln!(report).unwrap();
+    writeln!(report, "Round score distribution").unwrap();
+
+    for standing in standings {
+        let distribution = standing
+            .round_scores
+            .iter()
+            .map(|(score, rounds)| format!("{score}:{rounds}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        writeln!(report, "{:<18} | {}", standing.name, distribution).unwrap();
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entrants(seed: u64) -> Vec<Entrant> {
+        vec![
+            Entrant::new("rock", || Fixed::new(vec![RPS::Rock])),
+            Entrant::new("random", move || Random::new(seed)),
+            Entrant::new("frequency", || FrequencyCounter),
+        ]
+    }
+
+    #[test]
+    fn same_seed_same_results() {
+        assert_eq!(
+            run(&Game::aoc_2022(), &entrants(7), 100),
+            run(&Game::aoc_2022(), &entrants(7), 100)
+        );
+        assert_ne!(
+            run(&Game::aoc_2022(), &entrants(7), 100),
+            run(&Game::aoc_2022(), &entrants(8), 100)
+        );
+    }
+
+    #[test]
+    fn frequency_counter_exploits_a_fixed_player() {
+        let standings = run(&Game::aoc_2022(), &entrants(7), 100);
+        let rock = &standings[0];
+        let frequency = &standings[2];
+
+        // With no history it expects rock too, so it wins every round
+        assert_eq!(rock.match_scores[1], 100);
+        assert_eq!(frequency.match_scores[0], 100 * (2 + 6));
+        assert!(frequency.win_rate() > rock.win_rate());
+    }
+
+    #[test]
+    fn strategy_guide_ends_rounds_as_written() {
+        let guide = vec![
+            (RPS::Rock, ExpectedResult::Tie),
+            (RPS::Paper, ExpectedResult::Win),
+            (RPS::Scissors, ExpectedResult::Lose),
+        ];
+        let game = Game::aoc_2022();
+        let mut player = StrategyGuide::new(guide);
+
+        assert_eq!(player.play(&game, &[]), RPS::Rock);
+        assert_eq!(player.play(&game, &[(RPS::Rock, RPS::Rock)]), RPS::Scissors);
+        assert_eq!(player.play(&game, &[(RPS::Rock, RPS::Rock); 2]), RPS::Paper);
+        assert_eq!(player.play(&game, &[(RPS::Rock, RPS::Rock); 3]), RPS::Rock);
+    }
+
+    #[test]
+    fn no_rounds_played() {
+        let standings = run(&Game::aoc_2022(), &entrants(7), 0);
+
+        assert_eq!(standings[0].win_rate(), 0.0);
+        assert_eq!(standings[0].mean_score(), 0.0);
+        assert!(!report(&standings).contains("NaN"));
+    }
+}
@@ -0,0 +1,160 @@
+//! Compares the `ItemSet` bitmask against the `HashSet<char>` implementation it replaced, on
+//! generated inputs much larger than the puzzle one.
+
+use std::collections::{HashMap, HashSet};
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use day_03::item_set::ItemSet;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Rucksacks in groups of three with compartments of 8 to 32 items, keeping the puzzle invariants:
+/// exactly one item type in both compartments and exactly one badge per group.
+fn generate(groups: usize) -> String {
+    let mut state: u64 = 2022;
+    let mut next = move |max: usize| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+
+        (state >> 33) as usize % max
+    };
+
+    let mut input = String::new();
+    for _ in 0..groups {
+        let mut items = ITEMS.iter().map(|c| *c as char).collect::<Vec<_>>();
+        for i in (1..items.len()).rev() {
+            items.swap(i, next(i + 1));
+        }
+
+        // Each elf only uses items from its own pool, so the badge is the only shared one
+        let badge = items.pop().unwrap();
+
+        for pool in items.chunks(17) {
+            let (pool_a, pool_b) = pool.split_at(8);
+            let duplicate = pool_a[next(pool_a.len())];
+            let len = 8 + next(24);
+
+            let mut compartment_a = (0..len)
+                .map(|_| pool_a[next(pool_a.len())])
+                .collect::<Vec<_>>();
+            let mut compartment_b = (0..len)
+                .map(|_| pool_b[next(pool_b.len())])
+                .collect::<Vec<_>>();
+
+            compartment_a[0] = badge;
+            compartment_a[1 + next(len - 1)] = duplicate;
+            compartment_b[next(len)] = duplicate;
+
+            input.extend(compartment_a);
+            input.extend(compartment_b);
+            input.push('\n');
+        }
+    }
+
+    input
+}
+
+fn priorities() -> HashMap<char, usize> {
+    ITEMS
+        .iter()
+        .enumerate()
+        .map(|(index, c)| (*c as char, index + 1))
+        .collect()
+}
+
+fn hash_set_part_1(input: &str, priorities: &HashMap<char, usize>) -> usize {
+    input
+        .lines()
+        .filter_map(|line| {
+            let (a, b) = line.split_at(line.len() / 2);
+            let a = a.chars().collect::<HashSet<_>>();
+
+            b.chars().find(|c| a.contains(c))
+        })
+        .map(|c| priorities[&c])
+        .sum()
+}
+
+fn hash_set_part_2(input: &str, priorities: &HashMap<char, usize>) -> usize {
+    input
+        .lines()
+        .collect::<Vec<_>>()
+        .chunks(3)
+        .filter_map(|chunk| {
+            let a = chunk[0].chars().collect::<HashSet<_>>();
+            let b = chunk[1].chars().collect::<HashSet<_>>();
+
+            chunk[2].chars().find(|c| a.contains(c) && b.contains(c))
+        })
+        .map(|c| priorities[&c])
+        .sum()
+}
+
+fn item_set_part_1(input: &str) -> u32 {
+    input
+        .lines()
+        .filter_map(|line| {
+            let (a, b) = line.split_at(line.len() / 2);
+
+            ItemSet::from_items(a)
+                .intersection(ItemSet::from_items(b))
+                .first()
+        })
+        .map(|c| ItemSet::priority(c).unwrap())
+        .sum()
+}
+
+fn item_set_part_2(input: &str) -> u32 {
+    let mut lines = input.lines().map(ItemSet::from_items);
+    let mut sum = 0;
+
+    while let (Some(a), Some(b), Some(c)) = (lines.next(), lines.next(), lines.next()) {
+        if let Some(badge) = a.intersection(b).intersection(c).first() {
+            sum += ItemSet::priority(badge).unwrap();
+        }
+    }
+
+    sum
+}
+
+fn bench(c: &mut Criterion) {
+    let priorities = priorities();
+
+    for groups in [1_000, 100_000] {
+        let input = generate(groups);
+
+        // Both implementations have to agree before comparing them
+        assert_eq!(
+            hash_set_part_1(&input, &priorities) as u32,
+            item_set_part_1(&input)
+        );
+        assert_eq!(
+            hash_set_part_2(&input, &priorities) as u32,
+            item_set_part_2(&input)
+        );
+
+        let mut group = c.benchmark_group("part 1");
+        group.bench_function(BenchmarkId::new("hash set", groups), |b| {
+            b.iter(|| hash_set_part_1(black_box(&input), &priorities))
+        });
+        group.bench_function(BenchmarkId::new("item set", groups), |b| {
+            b.iter(|| item_set_part_1(black_box(&input)))
+        });
+        group.finish();
+
+        let mut group = c.benchmark_group("part 2");
+        group.bench_function(BenchmarkId::new("hash set", groups), |b| {
+            b.iter(|| hash_set_part_2(black_box(&input), &priorities))
+        });
+        group.bench_function(BenchmarkId::new("item set", groups), |b| {
+            b.iter(|| item_set_part_2(black_box(&input)))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
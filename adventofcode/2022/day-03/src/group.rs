@@ -0,0 +1,144 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::item_set::ItemSet;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GroupError {
+    InvalidSize,
+    /// The amount of rucksacks is not a multiple of the group size.
+    Incomplete {
+        rucksacks: usize,
+        size: usize,
+    },
+    /// `group` starts at 1.
+    NoBadge {
+        group: usize,
+    },
+    MultipleBadges {
+        group: usize,
+        items: ItemSet,
+    },
+}
+
+impl Display for GroupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSize => write!(f, "group size must be at least 1"),
+            Self::Incomplete { rucksacks, size } => write!(
+                f,
+                "{rucksacks} rucksacks can't be split in groups of {size}, {} left over",
+                rucksacks % size
+            ),
+            Self::NoBadge { group } => write!(f, "group {group} has no item in common"),
+            Self::MultipleBadges { group, items } => write!(
+                f,
+                "group {group} has {} items in common: {}",
+                items.len(),
+                items.iter().collect::<String>()
+            ),
+        }
+    }
+}
+
+impl Error for GroupError {}
+
+/// The items every elf of a group carries.
+pub struct ElfGroup {
+    common: ItemSet,
+}
+
+impl ElfGroup {
+    pub fn new<'a, I: IntoIterator<Item = &'a str>>(elves: I) -> Self {
+        let common = elves
+            .into_iter()
+            .map(ItemSet::from_items)
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default();
+
+        ElfGroup { common }
+    }
+
+    /// The only item type carried by every elf, `group` is used for the error.
+    pub fn get_group_badge(&self, group: usize) -> Result<char, GroupError> {
+        match self.common.len() {
+            0 => Err(GroupError::NoBadge { group }),
+            1 => Ok(self.common.first().unwrap()),
+            _ => Err(GroupError::MultipleBadges {
+                group,
+                items: self.common,
+            }),
+        }
+    }
+}
+
+/// Splits the rucksacks in groups of `size` and finds the badge of each one.
+pub fn group_badges(
+    input: &str,
+    size: usize,
+) -> Result<impl Iterator<Item = Result<char, GroupError>> + '_, GroupError> {
+    if size == 0 {
+        return Err(GroupError::InvalidSize);
+    }
+
+    let rucksacks = input.lines().count();
+    if !rucksacks.is_multiple_of(size) {
+        return Err(GroupError::Incomplete { rucksacks, size });
+    }
+
+    let mut lines = input.lines();
+
+    Ok((1..=rucksacks / size)
+        .map(move |group| ElfGroup::new(lines.by_ref().take(size)).get_group_badge(group)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn groups_of_three() {
+        let badges = group_badges(INPUT, 3)
+            .unwrap()
+            .collect::<Result<String, _>>();
+
+        assert_eq!(badges, Ok("rZ".to_string()));
+    }
+
+    #[test]
+    fn line_count_not_divisible() {
+        assert_eq!(
+            group_badges(INPUT, 4).err(),
+            Some(GroupError::Incomplete {
+                rucksacks: 6,
+                size: 4
+            })
+        );
+    }
+
+    #[test]
+    fn reports_groups_without_a_single_badge() {
+        let badges = group_badges(INPUT, 2).unwrap().collect::<Vec<_>>();
+
+        assert_eq!(
+            badges[2],
+            Err(GroupError::MultipleBadges {
+                group: 3,
+                items: ItemSet::from_items("GJZ")
+            })
+        );
+
+        let badges = group_badges("abcd\ncdef\nefgh", 3)
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        assert_eq!(badges, vec![Err(GroupError::NoBadge { group: 1 })]);
+    }
+}
@@ -0,0 +1,154 @@
+use std::fmt::{self, Debug, Formatter};
+
+/// Set of item types stored as a bitmask, bit `n` is the item with priority `n + 1`
+/// (`a-z` are bits 0 to 25 and `A-Z` bits 26 to 51).
+#[derive(Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    /// Bit of the item, `None` if it's not a valid item type.
+    pub(crate) fn bit(item: char) -> Option<u32> {
+        match item {
+            'a'..='z' => Some(item as u32 - 'a' as u32),
+            'A'..='Z' => Some(item as u32 - 'A' as u32 + 26),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn item(bit: u32) -> char {
+        match bit {
+            0..=25 => (b'a' + bit as u8) as char,
+            _ => (b'A' + (bit - 26) as u8) as char,
+        }
+    }
+
+    /// Priority of an item type, `None` if it's not a valid item type.
+    pub fn priority(item: char) -> Option<u32> {
+        ItemSet::bit(item).map(|bit| bit + 1)
+    }
+
+    /// Items of `s`, anything that is not an item type is ignored.
+    pub fn from_items(s: &str) -> Self {
+        s.chars()
+            .filter_map(ItemSet::bit)
+            .fold(ItemSet::EMPTY, |set, bit| ItemSet(set.0 | 1 << bit))
+    }
+
+    pub fn insert(&mut self, item: char) -> bool {
+        match ItemSet::bit(item) {
+            Some(bit) => {
+                self.0 |= 1 << bit;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        ItemSet::bit(item).is_some_and(|bit| self.0 & 1 << bit != 0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Item with the lowest priority.
+    pub fn first(&self) -> Option<char> {
+        self.iter().next()
+    }
+
+    /// Sum of the priorities of every item in the set.
+    pub fn priority_sum(&self) -> u32 {
+        self.iter()
+            .map(|item| ItemSet::priority(item).unwrap())
+            .sum()
+    }
+
+    /// Items in priority order.
+    pub fn iter(&self) -> Iter {
+        Iter(self.0)
+    }
+}
+
+impl Debug for ItemSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+pub struct Iter(u64);
+
+impl Iterator for Iter {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let bit = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+
+        Some(ItemSet::item(bit))
+    }
+}
+
+impl IntoIterator for ItemSet {
+    type Item = char;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<char> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut set = ItemSet::EMPTY;
+        for item in iter {
+            set.insert(item);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priorities() {
+        assert_eq!(ItemSet::priority('a'), Some(1));
+        assert_eq!(ItemSet::priority('z'), Some(26));
+        assert_eq!(ItemSet::priority('A'), Some(27));
+        assert_eq!(ItemSet::priority('Z'), Some(52));
+        assert_eq!(ItemSet::priority('1'), None);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = ItemSet::from_items("vJrwpWtwJgWr");
+        let b = ItemSet::from_items("hcsFMMfFFhFp");
+
+        assert_eq!(a.intersection(b).iter().collect::<String>(), "p");
+        assert_eq!(a.union(b).len(), 14);
+        assert_eq!(a.iter().collect::<String>(), "gprtvwJW");
+        assert!(a.contains('J') && !a.contains('j'));
+        assert_eq!(ItemSet::from_items("pL").priority_sum(), 16 + 38);
+    }
+}
@@ -0,0 +1,84 @@
+pub mod group;
+pub mod item_set;
+pub mod planner;
+pub mod rucksack;
+
+use aoc::{PartResult, Solution};
+
+use group::group_badges;
+use item_set::ItemSet;
+use rucksack::{Diagnostic, Rucksack};
+
+/// Sums the priorities of the duplicates, then of the badges of every `group_size` elves.
+pub struct Day03 {
+    /// Rejects rucksacks with an odd length or an item that isn't a letter.
+    pub strict: bool,
+    pub group_size: usize,
+}
+
+impl Default for Day03 {
+    fn default() -> Self {
+        Day03 {
+            strict: false,
+            group_size: 3,
+        }
+    }
+}
+
+impl Solution for Day03 {
+    fn part_1(&self, input: &str) -> PartResult {
+        let mut sum = 0;
+
+        for (index, line) in input.lines().enumerate() {
+            let rucksack = match self.strict {
+                true => Rucksack::parse_strict(line)
+                    .map_err(|err| format!("line {}: {}", index + 1, err))?,
+                false => line.parse::<Rucksack>().unwrap(),
+            };
+            let duplicate = rucksack
+                .get_duplicate()
+                .ok_or_else(|| format!("line {}: {}", index + 1, Diagnostic::NoDuplicate))?;
+
+            sum += ItemSet::priority(duplicate).unwrap();
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part_2(&self, input: &str) -> Option<PartResult> {
+        let badges = match group_badges(input, self.group_size) {
+            Ok(badges) => badges,
+            Err(err) => return Some(Err(err.into())),
+        };
+
+        let mut sum = 0;
+        let mut errors = vec![];
+
+        for badge in badges {
+            match badge {
+                Ok(badge) => sum += ItemSet::priority(badge).unwrap(),
+                Err(err) => errors.push(err.to_string()),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Some(Err(errors.join("\n").into()));
+        }
+
+        Some(Ok(sum.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::test_examples!(2022, 3, Day03::default());
+
+    #[test]
+    fn rucksack_without_duplicate() {
+        let err = Day03::default().part_1("abca\nabcd\n").unwrap_err();
+
+        assert_eq!(err.to_string(), "line 2: no item type in both compartments");
+    }
+}
@@ -1,93 +1,91 @@
-use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::process;
 
-struct Rucksack {
-    compartment_a: String,
-    compartment_b: String,
+use day_03::planner;
+use day_03::rucksack::Rucksack;
+use day_03::Day03;
+
+const USAGE: &str = "Usage: day-03 [--input <path>|-] [--example <name>] [--strict] [--group-size N] | diagnose | plan [--group-size N]";
+
+enum Command {
+    Answer {
+        strict: bool,
+        group_size: usize,
+    },
+    /// Report every rucksack that breaks the puzzle rules.
+    Diagnose,
+    /// Item moves that would make every rucksack and group follow the rules.
+    Plan {
+        group_size: usize,
+    },
 }
 
-impl Rucksack {
-    fn get_duplicate(&self) -> Option<char> {
-        let compartment_a = self.compartment_a.chars().collect::<HashSet<_>>();
-        self.compartment_b
-            .chars()
-            .into_iter()
-            .find(|c| compartment_a.contains(c))
+fn parse_args(args: &[String]) -> Result<Command, Box<dyn Error>> {
+    if let [cmd] = args {
+        if cmd == "diagnose" {
+            return Ok(Command::Diagnose);
+        }
     }
-}
 
-impl std::str::FromStr for Rucksack {
-    type Err = ();
+    let (planning, args) = match args {
+        [cmd, rest @ ..] if cmd == "plan" => (true, rest),
+        _ => (false, args),
+    };
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let len = s.len();
-        let (compartment_a, compartment_b) = s.split_at(len / 2);
+    let mut strict = false;
+    let mut group_size = 3;
 
-        Ok(Rucksack {
-            compartment_a: compartment_a.to_string(),
-            compartment_b: compartment_b.to_string(),
-        })
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--strict" if !planning => strict = true,
+            "--group-size" => group_size = args.next().ok_or(USAGE)?.parse()?,
+            _ => return Err(USAGE.into()),
+        }
     }
-}
 
-struct ElfGroup {
-    elf_a: String,
-    elf_b: String,
-    elf_c: String,
+    if planning {
+        return Ok(Command::Plan { group_size });
+    }
+
+    Ok(Command::Answer { strict, group_size })
 }
 
-impl ElfGroup {
-    fn new(elf_a: &str, elf_b: &str, elf_c: &str) -> Self {
-        ElfGroup {
-            elf_a: elf_a.to_string(),
-            elf_b: elf_b.to_string(),
-            elf_c: elf_c.to_string(),
+fn diagnose(input: &str) {
+    let mut errors = 0;
+
+    for (index, line) in input.lines().enumerate() {
+        if let Some(diagnostic) = Rucksack::diagnose(line) {
+            println!("line {}: {}", index + 1, diagnostic);
+            errors += 1;
         }
     }
 
-    fn get_group_badge(&self) -> char {
-        let bag_a = self.elf_a.chars().collect::<HashSet<_>>();
-        let bag_b = self.elf_b.chars().collect::<HashSet<_>>();
-
-        self.elf_c
-            .chars()
-            .into_iter()
-            .find(|c| bag_a.contains(c) && bag_b.contains(c))
-            .unwrap()
+    if errors > 0 {
+        process::exit(1);
     }
+
+    println!("No problems found");
 }
 
 fn main() {
-    let input = include_str!("../input.txt");
+    let (input, args) = aoc::input::from_args(env!("CARGO_MANIFEST_DIR")).unwrap();
     let input = input.trim();
 
-    let chars_priority = ('a'..='z')
-        .chain('A'..='Z')
-        .enumerate()
-        .map(|(index, c)| (c, index + 1));
-
-    let chars_priority: HashMap<char, usize> = HashMap::from_iter(chars_priority);
-
-    let result_1 = input
-        .lines()
-        .map(|line| line.parse::<Rucksack>().unwrap())
-        .map(|rucksack| rucksack.get_duplicate().unwrap())
-        .map(|c| chars_priority.get(&c).unwrap())
-        .sum::<usize>();
-
-    let result_2 = input
-        .lines()
-        .collect::<Vec<&str>>()
-        .chunks(3)
-        .map(|chunk| {
-            match chunk {
-                [elf_a, elf_b, elf_c] => ElfGroup::new(elf_a, elf_b, elf_c),
-                _ => panic!("Chunk is not 3 elements"),
+    let (strict, group_size) = match parse_args(&args).unwrap() {
+        Command::Diagnose => return diagnose(input),
+        Command::Plan { group_size } => {
+            match planner::plan(input, group_size) {
+                Ok(plan) => println!("{plan}"),
+                Err(err) => {
+                    eprintln!("{err}");
+                    process::exit(1);
+                }
             }
-        })
-        .map(|elf_group| elf_group.get_group_badge())
-        .map(|c| chars_priority.get(&c).unwrap())
-        .sum::<usize>();
+            return;
+        }
+        Command::Answer { strict, group_size } => (strict, group_size),
+    };
 
-    dbg!(result_1);
-    dbg!(result_2);
+    aoc::print_answers(2022, 3, &Day03 { strict, group_size }, input);
 }
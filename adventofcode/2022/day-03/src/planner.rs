@@ -0,0 +1,470 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::group::GroupError;
+use crate::item_set::ItemSet;
+use crate::rucksack::{Rucksack, RucksackError};
+
+/// Amount of each item type, indexed by its bit in `ItemSet`.
+type Counts = [usize; 52];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compartment {
+    First,
+    Second,
+}
+
+impl Compartment {
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_index(index: usize) -> Self {
+        [Compartment::First, Compartment::Second][index]
+    }
+}
+
+impl Display for Compartment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::First => write!(f, "first"),
+            Self::Second => write!(f, "second"),
+        }
+    }
+}
+
+/// `count` items of the same type going from one compartment to another one of the same group,
+/// rucksacks are numbered from 1 in input order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub item: char,
+    pub count: usize,
+    pub from: (usize, Compartment),
+    pub to: (usize, Compartment),
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:?} from the {} compartment to the {} compartment",
+            self.count, self.item, self.from.1, self.to.1
+        )?;
+
+        if self.to.0 != self.from.0 {
+            write!(f, " of rucksack {}", self.to.0)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Plan {
+    pub moves: Vec<Move>,
+}
+
+impl Plan {
+    /// Amount of items moved.
+    pub fn total(&self) -> usize {
+        self.moves.iter().map(|m| m.count).sum()
+    }
+
+    /// Moves grouped by the rucksack the items are taken from.
+    pub fn by_rucksack(&self) -> BTreeMap<usize, Vec<&Move>> {
+        let mut rucksacks = BTreeMap::<_, Vec<_>>::new();
+        for m in &self.moves {
+            rucksacks.entry(m.from.0).or_default().push(m);
+        }
+
+        rucksacks
+    }
+
+    fn push(&mut self, item: usize, count: usize, from: (usize, usize), to: (usize, usize)) {
+        if count == 0 {
+            return;
+        }
+
+        let from = (from.0, Compartment::from_index(from.1));
+        let to = (to.0, Compartment::from_index(to.1));
+        let item = ItemSet::item(item as u32);
+
+        match self.moves.last_mut() {
+            Some(last) if (last.item, last.from, last.to) == (item, from, to) => {
+                last.count += count
+            }
+            _ => self.moves.push(Move {
+                item,
+                count,
+                from,
+                to,
+            }),
+        }
+    }
+}
+
+impl Display for Plan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (rucksack, moves) in self.by_rucksack() {
+            writeln!(f, "rucksack {rucksack}")?;
+            for m in moves {
+                writeln!(f, "  {m}")?;
+            }
+        }
+
+        write!(f, "{} items moved", self.total())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PlanError {
+    /// `line` starts at 1.
+    Rucksack {
+        line: usize,
+        err: RucksackError,
+    },
+    Group(GroupError),
+    /// Not even moving items around gives the group a single badge.
+    Unsolvable {
+        group: usize,
+    },
+}
+
+impl Display for PlanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rucksack { line, err } => write!(f, "line {line}: {err}"),
+            Self::Group(err) => write!(f, "{err}"),
+            Self::Unsolvable { group } => {
+                write!(f, "group {group} can't end up with a single badge")
+            }
+        }
+    }
+}
+
+impl Error for PlanError {}
+
+impl From<GroupError> for PlanError {
+    fn from(err: GroupError) -> Self {
+        PlanError::Group(err)
+    }
+}
+
+fn counts(items: &str) -> Counts {
+    let mut counts = [0; 52];
+    for item in items.chars() {
+        counts[ItemSet::bit(item).unwrap() as usize] += 1;
+    }
+
+    counts
+}
+
+/// Compartment an item type stays in when it's in both, the one with more of them.
+fn kept(counts: [usize; 2]) -> usize {
+    usize::from(counts[1] > counts[0])
+}
+
+/// Moves needed to get an item type out of one of the compartments.
+fn fix_cost(counts: [usize; 2]) -> usize {
+    counts[0].min(counts[1])
+}
+
+struct Group<'a> {
+    /// Number of the first rucksack of the group.
+    start: usize,
+    rucksacks: &'a [[Counts; 2]],
+}
+
+impl Group<'_> {
+    fn counts(&self, rucksack: usize, item: usize) -> [usize; 2] {
+        let [a, b] = &self.rucksacks[rucksack];
+        [a[item], b[item]]
+    }
+
+    fn carried_by(&self, item: usize) -> Vec<usize> {
+        (0..self.rucksacks.len())
+            .filter(|&r| self.counts(r, item) != [0, 0])
+            .collect()
+    }
+
+    /// Cheapest rucksack to take every `item` out of, and how much more than fixing its
+    /// compartments that costs.
+    fn cheapest_eviction(&self, item: usize) -> (usize, usize) {
+        (0..self.rucksacks.len())
+            .map(|r| {
+                let [a, b] = self.counts(r, item);
+                (a.max(b), r)
+            })
+            .min()
+            .unwrap()
+    }
+
+    /// Moves needed to give every rucksack a copy of `item` on top of fixing its compartments,
+    /// `None` when there are not enough.
+    fn introduction_cost(&self, item: usize) -> Option<usize> {
+        let counts = (0..self.rucksacks.len())
+            .map(|r| self.counts(r, item))
+            .collect::<Vec<_>>();
+
+        let total = counts.iter().map(|[a, b]| a + b).sum::<usize>();
+        if total < self.rucksacks.len() {
+            return None;
+        }
+
+        // Items taken out of a compartment can go to the rucksacks missing them for free
+        let spare = counts.iter().map(|&c| fix_cost(c)).sum::<usize>();
+        let missing = self.rucksacks.len() - self.carried_by(item).len();
+
+        Some(missing.saturating_sub(spare))
+    }
+
+    fn fix(&self, plan: &mut Plan, rucksack: usize, item: usize) {
+        let counts = self.counts(rucksack, item);
+        let kept = kept(counts);
+        let number = self.start + rucksack;
+
+        plan.push(item, fix_cost(counts), (number, 1 - kept), (number, kept));
+    }
+
+    fn evict(&self, plan: &mut Plan, from: usize, item: usize) {
+        let to = (from + 1) % self.rucksacks.len();
+        let to_compartment = kept(self.counts(to, item));
+
+        for (compartment, count) in self.counts(from, item).into_iter().enumerate() {
+            plan.push(
+                item,
+                count,
+                (self.start + from, compartment),
+                (self.start + to, to_compartment),
+            );
+        }
+
+        for rucksack in (0..self.rucksacks.len()).filter(|&r| r != from) {
+            self.fix(plan, rucksack, item);
+        }
+    }
+
+    fn introduce(&self, plan: &mut Plan, item: usize) {
+        let carried_by = self.carried_by(item);
+        let mut missing = (0..self.rucksacks.len())
+            .filter(|r| !carried_by.contains(r))
+            .map(|r| (self.start + r, 0));
+
+        // First what has to leave its compartment anyway, then whatever is left over
+        for &r in &carried_by {
+            let counts = self.counts(r, item);
+            let kept = kept(counts);
+            let from = (self.start + r, 1 - kept);
+
+            for _ in 0..fix_cost(counts) {
+                let to = missing.next().unwrap_or((self.start + r, kept));
+                plan.push(item, 1, from, to);
+            }
+        }
+
+        for &r in &carried_by {
+            let counts = self.counts(r, item);
+            let kept = kept(counts);
+
+            for _ in 1..counts[kept] {
+                match missing.next() {
+                    Some(to) => plan.push(item, 1, (self.start + r, kept), to),
+                    None => return,
+                }
+            }
+        }
+    }
+
+    fn plan(&self, plan: &mut Plan) -> Option<()> {
+        let common = (0..52)
+            .filter(|&item| self.carried_by(item).len() == self.rucksacks.len())
+            .collect::<Vec<_>>();
+
+        let mut evicted = vec![];
+        let mut introduced = None;
+
+        if common.is_empty() {
+            introduced = (0..52)
+                .filter_map(|item| Some((self.introduction_cost(item)?, item)))
+                .min()
+                .map(|(_, item)| item);
+            introduced?;
+        } else {
+            // Items can only leave a rucksack for another one of the group
+            if common.len() > 1 && self.rucksacks.len() == 1 {
+                return None;
+            }
+
+            // The badge is the item that would be the most expensive to get rid of
+            let badge = common
+                .iter()
+                .copied()
+                .rev()
+                .max_by_key(|&item| self.cheapest_eviction(item).0)
+                .unwrap();
+
+            evicted = common
+                .into_iter()
+                .filter(|&item| item != badge)
+                .map(|item| (item, self.cheapest_eviction(item).1))
+                .collect();
+        }
+
+        for item in 0..52 {
+            if let Some(&(_, from)) = evicted.iter().find(|(evicted, _)| *evicted == item) {
+                self.evict(plan, from, item);
+            } else if introduced == Some(item) {
+                self.introduce(plan, item);
+            } else {
+                for rucksack in 0..self.rucksacks.len() {
+                    self.fix(plan, rucksack, item);
+                }
+            }
+        }
+
+        Some(())
+    }
+}
+
+/// Fewest item moves that leave every rucksack without items in both compartments and every
+/// group of `size` with a single badge. Items can move between compartments of a rucksack or to
+/// another rucksack of the same group, so compartments may end up with different sizes.
+pub fn plan(input: &str, size: usize) -> Result<Plan, PlanError> {
+    if size == 0 {
+        return Err(GroupError::InvalidSize.into());
+    }
+
+    let rucksacks = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            Rucksack::parse_strict(line).map_err(|err| PlanError::Rucksack {
+                line: index + 1,
+                err,
+            })?;
+
+            let (a, b) = line.split_at(line.len() / 2);
+
+            Ok([counts(a), counts(b)])
+        })
+        .collect::<Result<Vec<_>, PlanError>>()?;
+
+    if !rucksacks.len().is_multiple_of(size) {
+        return Err(GroupError::Incomplete {
+            rucksacks: rucksacks.len(),
+            size,
+        }
+        .into());
+    }
+
+    let mut plan = Plan::default();
+
+    for (index, rucksacks) in rucksacks.chunks(size).enumerate() {
+        let group = Group {
+            start: index * size + 1,
+            rucksacks,
+        };
+
+        group
+            .plan(&mut plan)
+            .ok_or(PlanError::Unsolvable { group: index + 1 })?;
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::group::ElfGroup;
+
+    const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    /// Compartments of every rucksack after following the plan.
+    fn apply(input: &str, plan: &Plan) -> Vec<[String; 2]> {
+        let mut rucksacks = input
+            .lines()
+            .map(|line| {
+                let (a, b) = line.split_at(line.len() / 2);
+                [a.to_string(), b.to_string()]
+            })
+            .collect::<Vec<_>>();
+
+        for m in &plan.moves {
+            for _ in 0..m.count {
+                let from = &mut rucksacks[m.from.0 - 1][m.from.1.index()];
+                from.remove(from.find(m.item).unwrap());
+                rucksacks[m.to.0 - 1][m.to.1.index()].push(m.item);
+            }
+        }
+
+        rucksacks
+    }
+
+    fn assert_solved(input: &str, size: usize, total: usize) {
+        let plan = plan(input, size).unwrap();
+        let rucksacks = apply(input, &plan);
+
+        for [a, b] in &rucksacks {
+            assert!(ItemSet::from_items(a)
+                .intersection(ItemSet::from_items(b))
+                .is_empty());
+        }
+
+        for (index, group) in rucksacks.chunks(size).enumerate() {
+            let elves = group
+                .iter()
+                .map(|[a, b]| format!("{a}{b}"))
+                .collect::<Vec<_>>();
+            let badge = ElfGroup::new(elves.iter().map(String::as_str)).get_group_badge(index + 1);
+
+            assert!(badge.is_ok(), "{badge:?}");
+        }
+
+        assert_eq!(plan.total(), total);
+    }
+
+    #[test]
+    fn only_compartments_to_fix() {
+        assert_solved(INPUT, 3, 9);
+
+        let plan = plan("vJrwpWtwJgWrhcsFMMfFFhFp", 1);
+        assert_eq!(plan, Err(PlanError::Unsolvable { group: 1 }));
+    }
+
+    #[test]
+    fn keeps_the_most_expensive_badge() {
+        // `a` and `b` are both in every rucksack, moving the `b` of the first one is cheapest
+        assert_solved("abcd\nabab", 2, 3);
+        assert_solved(INPUT, 2, 20);
+    }
+
+    #[test]
+    fn brings_a_badge_in() {
+        assert_solved("aabc\ndefg", 2, 1);
+        // The `a` in the second compartment has to move anyway
+        assert_solved("abac\ndefg", 2, 1);
+        // Both `x` leaving the second compartment are enough, one of them goes to rucksack 2
+        assert_solved("xxyyxxab\ncdef", 2, 2);
+        assert_eq!(
+            plan("abcd\nefgh", 2),
+            Err(PlanError::Unsolvable { group: 1 })
+        );
+    }
+
+    #[test]
+    fn moves_per_rucksack() {
+        let plan = plan("aabc\ndefg", 2).unwrap();
+
+        assert_eq!(
+            plan.to_string(),
+            "rucksack 1\n  1 'a' from the first compartment to the first compartment of rucksack 2\n1 items moved"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::item_set::ItemSet;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RucksackError {
+    /// The items can't be split in two compartments of the same size.
+    OddLength(usize),
+    /// Something that is not `a-zA-Z`, `position` starts at 1.
+    InvalidItem { item: char, position: usize },
+}
+
+impl Display for RucksackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength(len) => write!(f, "odd amount of items ({len})"),
+            Self::InvalidItem { item, position } => {
+                write!(f, "invalid item {item:?} at position {position}")
+            }
+        }
+    }
+}
+
+impl Error for RucksackError {}
+
+/// Problems found in a rucksack when running diagnostics.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Diagnostic {
+    Invalid(RucksackError),
+    NoDuplicate,
+    MultipleDuplicates(ItemSet),
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(err) => write!(f, "{err}"),
+            Self::NoDuplicate => write!(f, "no item type in both compartments"),
+            Self::MultipleDuplicates(items) => write!(
+                f,
+                "{} item types in both compartments: {}",
+                items.len(),
+                items.iter().collect::<String>()
+            ),
+        }
+    }
+}
+
+pub struct Rucksack {
+    compartment_a: ItemSet,
+    compartment_b: ItemSet,
+}
+
+impl Rucksack {
+    /// Like `from_str` but rejects odd lengths and anything that is not an item type, instead of
+    /// ignoring it.
+    pub fn parse_strict(s: &str) -> Result<Self, RucksackError> {
+        if let Some((index, item)) = s
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_alphabetic())
+        {
+            return Err(RucksackError::InvalidItem {
+                item,
+                position: index + 1,
+            });
+        }
+
+        if !s.len().is_multiple_of(2) {
+            return Err(RucksackError::OddLength(s.len()));
+        }
+
+        Ok(Rucksack::split(s))
+    }
+
+    fn split(s: &str) -> Self {
+        let len = s.len();
+        let (compartment_a, compartment_b) = s.split_at(len / 2);
+
+        Rucksack {
+            compartment_a: ItemSet::from_items(compartment_a),
+            compartment_b: ItemSet::from_items(compartment_b),
+        }
+    }
+
+    /// Item types in both compartments.
+    pub fn duplicates(&self) -> ItemSet {
+        self.compartment_a.intersection(self.compartment_b)
+    }
+
+    pub fn get_duplicate(&self) -> Option<char> {
+        self.duplicates().first()
+    }
+
+    /// Everything wrong with the rucksack in `s`, if anything.
+    pub fn diagnose(s: &str) -> Option<Diagnostic> {
+        let rucksack = match Rucksack::parse_strict(s) {
+            Ok(rucksack) => rucksack,
+            Err(err) => return Some(Diagnostic::Invalid(err)),
+        };
+
+        let duplicates = rucksack.duplicates();
+
+        match duplicates.len() {
+            0 => Some(Diagnostic::NoDuplicate),
+            1 => None,
+            _ => Some(Diagnostic::MultipleDuplicates(duplicates)),
+        }
+    }
+}
+
+impl std::str::FromStr for Rucksack {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Rucksack::split(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strict_parsing() {
+        assert!(Rucksack::parse_strict("vJrwpWtwJgWrhcsFMMfFFhFp").is_ok());
+        assert_eq!(
+            Rucksack::parse_strict("abcab").err(),
+            Some(RucksackError::OddLength(5))
+        );
+        assert_eq!(
+            Rucksack::parse_strict("ab1b").err(),
+            Some(RucksackError::InvalidItem {
+                item: '1',
+                position: 3
+            })
+        );
+        assert_eq!(
+            Rucksack::parse_strict("aébé").err(),
+            Some(RucksackError::InvalidItem {
+                item: 'é',
+                position: 2
+            })
+        );
+    }
+
+    #[test]
+    fn diagnostics() {
+        assert_eq!(Rucksack::diagnose("abca"), None);
+        assert_eq!(Rucksack::diagnose("abcd"), Some(Diagnostic::NoDuplicate));
+        assert_eq!(
+            Rucksack::diagnose("abAcaA"),
+            Some(Diagnostic::MultipleDuplicates(ItemSet::from_items("aA")))
+        );
+    }
+}
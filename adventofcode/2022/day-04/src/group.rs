@@ -0,0 +1,352 @@
+use std::{error::Error, fmt};
+
+use crate::interval::Interval;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Not two sections separated by `-`.
+    InvalidRange(String),
+    InvalidSection(String),
+    /// The range ends before it starts.
+    Inverted {
+        from: usize,
+        to: usize,
+    },
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRange(text) => write!(f, "expected a range like 2-4, found {text:?}"),
+            Self::InvalidSection(text) => write!(f, "invalid section {text:?}"),
+            Self::Inverted { from, to } => write!(f, "range {from}-{to} ends before it starts"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input, starting at 1.
+    pub line: usize,
+    pub text: String,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {} in {:?}", self.line, self.kind, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone)]
+pub struct Elf {
+    pub sections: Interval<usize>,
+}
+
+impl Elf {
+    /// Reads `from-to`, with `normalise` an inverted range is swapped instead of rejected.
+    pub fn parse(s: &str, normalise: bool) -> Result<Self, ParseErrorKind> {
+        // Skip the first character so a minus sign is not taken as the separator
+        let separator = s
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .map(|(index, _)| index)
+            .ok_or_else(|| ParseErrorKind::InvalidRange(s.to_string()))?;
+
+        let section = |text: &str| {
+            text.trim()
+                .parse::<usize>()
+                .map_err(|_| ParseErrorKind::InvalidSection(text.to_string()))
+        };
+
+        let mut from = section(&s[..separator])?;
+        let mut to = section(&s[separator + 1..])?;
+
+        if from > to {
+            if !normalise {
+                return Err(ParseErrorKind::Inverted { from, to });
+            }
+
+            (from, to) = (to, from);
+        }
+
+        let end = to
+            .checked_add(1)
+            .ok_or_else(|| ParseErrorKind::InvalidSection(to.to_string()))?;
+
+        Ok(Elf {
+            sections: Interval::new(from, end).unwrap(),
+        })
+    }
+}
+
+impl std::str::FromStr for Elf {
+    type Err = ParseErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Elf::parse(s, false)
+    }
+}
+
+/// The elves of one line of the input, the 2022 puzzle always has two.
+#[derive(Debug, Clone)]
+pub struct ElfGroup {
+    pub elves: Vec<Elf>,
+}
+
+impl ElfGroup {
+    /// One of the elves has every section of the others.
+    pub fn some_contains_all(&self) -> bool {
+        let Some(hull) = self.elves.iter().map(|elf| elf.sections).reduce(|a, b| {
+            let start = *a.start().min(b.start());
+            let end = *a.end().max(b.end());
+
+            Interval::new(start, end).unwrap()
+        }) else {
+            return false;
+        };
+
+        self.elves.iter().any(|elf| elf.sections == hull)
+    }
+
+    /// At least two elves have a section in common.
+    pub fn any_overlap(&self) -> bool {
+        let mut elves = self
+            .elves
+            .iter()
+            .map(|elf| elf.sections)
+            .collect::<Vec<_>>();
+        elves.sort_unstable_by_key(|sections| *sections.start());
+
+        // Sorted by start, an elf overlaps a previous one if that one hasn't ended yet
+        let mut end = None;
+        for sections in elves {
+            if end.is_some_and(|end| end > *sections.start()) {
+                return true;
+            }
+
+            end = end.max(Some(*sections.end()));
+        }
+
+        false
+    }
+
+    /// Sections every elf is assigned to.
+    pub fn common_sections(&self) -> Option<Interval<usize>> {
+        self.elves
+            .iter()
+            .map(|elf| Some(elf.sections))
+            .reduce(|a, b| a?.intersection(&b?))?
+    }
+
+    /// Every elf has at least one section in common with all the others.
+    pub fn all_overlap(&self) -> bool {
+        self.common_sections().is_some()
+    }
+}
+
+impl ElfGroup {
+    pub fn parse(s: &str, normalise: bool) -> Result<Self, ParseErrorKind> {
+        let elves = s
+            .split(",")
+            .map(|elf| Elf::parse(elf, normalise))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ElfGroup { elves })
+    }
+}
+
+impl std::str::FromStr for ElfGroup {
+    type Err = ParseErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ElfGroup::parse(s, false)
+    }
+}
+
+/// The group of every line that isn't blank, with its line number in the input starting at 1.
+pub fn lines(
+    input: &str,
+    normalise: bool,
+) -> impl Iterator<Item = Result<(usize, ElfGroup), ParseError>> + '_ {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(move |(index, line)| {
+            let line_number = index + 1;
+
+            ElfGroup::parse(line, normalise)
+                .map(|group| (line_number, group))
+                .map_err(|kind| ParseError {
+                    line: line_number,
+                    text: line.to_string(),
+                    kind,
+                })
+        })
+}
+
+/// Reads every line of the input, stopping at the first error.
+pub fn parse(input: &str, normalise: bool) -> Result<Vec<ElfGroup>, ParseError> {
+    lines(input, normalise)
+        .map(|line| line.map(|(_, group)| group))
+        .collect()
+}
+
+/// Same as `parse` but goes through the whole input and returns every line with an error.
+pub fn parse_all(input: &str, normalise: bool) -> Result<Vec<ElfGroup>, Vec<ParseError>> {
+    let mut groups = vec![];
+    let mut errors = vec![];
+
+    for line in lines(input, normalise) {
+        match line {
+            Ok((_, group)) => groups.push(group),
+            Err(err) => errors.push(err),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(groups)
+    } else {
+        Err(errors)
+    }
+}
+
+/// What to count the groups for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Predicate {
+    ContainsAll,
+    AnyOverlap,
+    AllOverlap,
+}
+
+impl Predicate {
+    pub fn matches(self, group: &ElfGroup) -> bool {
+        match self {
+            Predicate::ContainsAll => group.some_contains_all(),
+            Predicate::AnyOverlap => group.any_overlap(),
+            Predicate::AllOverlap => group.all_overlap(),
+        }
+    }
+}
+
+impl std::str::FromStr for Predicate {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "contains-all" => Ok(Predicate::ContainsAll),
+            "any-overlap" => Ok(Predicate::AnyOverlap),
+            "all-overlap" => Ok(Predicate::AllOverlap),
+            _ => Err(format!("Unknown predicate {s:?}").into()),
+        }
+    }
+}
+
+impl fmt::Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Predicate::ContainsAll => write!(f, "contains-all"),
+            Predicate::AnyOverlap => write!(f, "any-overlap"),
+            Predicate::AllOverlap => write!(f, "all-overlap"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(s: &str) -> ElfGroup {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn pairs_behave_like_2022() {
+        let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+        let count = |predicate: Predicate| {
+            input
+                .lines()
+                .filter(|line| predicate.matches(&group(line)))
+                .count()
+        };
+
+        assert_eq!(count(Predicate::ContainsAll), 2);
+        assert_eq!(count(Predicate::AnyOverlap), 4);
+        assert_eq!(count(Predicate::AllOverlap), 4);
+    }
+
+    #[test]
+    fn larger_groups() {
+        let chain = group("1-3,3-5,5-7");
+        assert!(chain.any_overlap());
+        assert!(!chain.all_overlap());
+        assert!(!chain.some_contains_all());
+
+        let nested = group("2-9,3-4,4-8,1-1");
+        assert!(!nested.some_contains_all());
+        assert!(group("1-9,3-4,4-8,1-1").some_contains_all());
+
+        assert_eq!(group("1-6,3-8,2-5").common_sections(), Interval::new(3, 6));
+        assert!(!group("1-2,4-5,7-8").any_overlap());
+
+        let alone = group("7-7");
+        assert!(alone.some_contains_all() && alone.all_overlap() && !alone.any_overlap());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "60-15".parse::<Elf>().err(),
+            Some(ParseErrorKind::Inverted { from: 60, to: 15 })
+        );
+        assert_eq!(
+            Elf::parse("60-15", true).unwrap().sections,
+            Interval::new(15, 61).unwrap()
+        );
+        assert_eq!(
+            "-3-5".parse::<Elf>().err(),
+            Some(ParseErrorKind::InvalidSection("-3".to_string()))
+        );
+        assert_eq!(
+            "4".parse::<Elf>().err(),
+            Some(ParseErrorKind::InvalidRange("4".to_string()))
+        );
+
+        let err = parse("2-4,6-8\n\n3-x,1-2", false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3: invalid section \"x\" in \"3-x,1-2\""
+        );
+    }
+
+    #[test]
+    fn lines_keep_their_number() {
+        let numbers = lines("\n2-4,6-8\n\n  \n3-5,1-2\n", false)
+            .map(|line| line.unwrap().0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(numbers, vec![2, 5]);
+    }
+
+    #[test]
+    fn collects_every_problem() {
+        let errors = parse_all("2-4,6-8\n9-1,1-2\n1-2,\n1-2,3-4", false)
+            .unwrap_err()
+            .into_iter()
+            .map(|err| (err.line, err.kind))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (2, ParseErrorKind::Inverted { from: 9, to: 1 }),
+                (3, ParseErrorKind::InvalidRange(String::new())),
+            ]
+        );
+        assert!(parse_all("2-4,6-8\n9-1,1-2", true).is_ok());
+    }
+}
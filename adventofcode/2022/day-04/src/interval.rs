@@ -0,0 +1,264 @@
+use std::cmp::Ordering::{Equal, Greater, Less};
+use std::ops::Sub;
+
+/// Half-open interval `[start, end)`, never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+/// The 13 ways two intervals can be placed relative to each other (Allen's interval algebra),
+/// read as "a is <relation> b".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relation {
+    /// `aaa.....`
+    /// `.....bbb`
+    Before,
+    /// `aaa.....`
+    /// `...bbbbb`
+    Meets,
+    /// `aaaa....`
+    /// `..bbbbbb`
+    Overlaps,
+    /// `aaa.....`
+    /// `bbbbbbbb`
+    Starts,
+    /// `..aaa...`
+    /// `bbbbbbbb`
+    During,
+    /// `.....aaa`
+    /// `bbbbbbbb`
+    Finishes,
+    /// `aaaaaaaa`
+    /// `bbbbbbbb`
+    Equals,
+    /// Inverse of `Finishes`.
+    FinishedBy,
+    /// Inverse of `During`.
+    Contains,
+    /// Inverse of `Starts`.
+    StartedBy,
+    /// Inverse of `Overlaps`.
+    OverlappedBy,
+    /// Inverse of `Meets`.
+    MetBy,
+    /// Inverse of `Before`.
+    After,
+}
+
+impl Relation {
+    pub const ALL: [Relation; 13] = [
+        Relation::Before,
+        Relation::Meets,
+        Relation::Overlaps,
+        Relation::Starts,
+        Relation::During,
+        Relation::Finishes,
+        Relation::Equals,
+        Relation::FinishedBy,
+        Relation::Contains,
+        Relation::StartedBy,
+        Relation::OverlappedBy,
+        Relation::MetBy,
+        Relation::After,
+    ];
+
+    /// The relation of b to a.
+    pub fn inverse(self) -> Relation {
+        Relation::ALL[Relation::ALL.len() - 1 - self as usize]
+    }
+
+    /// Whether the relation holds, straight from the definition on the endpoints.
+    pub fn holds<T: Ord>(self, a: &Interval<T>, b: &Interval<T>) -> bool {
+        match self {
+            Relation::Before => a.end < b.start,
+            Relation::Meets => a.end == b.start,
+            Relation::Overlaps => a.start < b.start && b.start < a.end && a.end < b.end,
+            Relation::Starts => a.start == b.start && a.end < b.end,
+            Relation::During => b.start < a.start && a.end < b.end,
+            Relation::Finishes => b.start < a.start && a.end == b.end,
+            Relation::Equals => a.start == b.start && a.end == b.end,
+            inverse => inverse.inverse().holds(b, a),
+        }
+    }
+
+    /// One of the intervals has every point of the other one.
+    pub fn is_containment(self) -> bool {
+        matches!(
+            self,
+            Relation::Starts
+                | Relation::During
+                | Relation::Finishes
+                | Relation::Equals
+                | Relation::FinishedBy
+                | Relation::Contains
+                | Relation::StartedBy
+        )
+    }
+
+    /// The intervals have at least one point in common.
+    pub fn is_overlap(self) -> bool {
+        !matches!(
+            self,
+            Relation::Before | Relation::Meets | Relation::MetBy | Relation::After
+        )
+    }
+}
+
+impl<T: Ord> Interval<T> {
+    /// `None` when `start >= end`.
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start < end).then_some(Interval { start, end })
+    }
+
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+
+    pub fn relation(&self, other: &Interval<T>) -> Relation {
+        if self.end <= other.start {
+            return match self.end == other.start {
+                true => Relation::Meets,
+                false => Relation::Before,
+            };
+        }
+
+        if other.end <= self.start {
+            return match other.end == self.start {
+                true => Relation::MetBy,
+                false => Relation::After,
+            };
+        }
+
+        match (self.start.cmp(&other.start), self.end.cmp(&other.end)) {
+            (Less, Less) => Relation::Overlaps,
+            (Less, Equal) => Relation::FinishedBy,
+            (Less, Greater) => Relation::Contains,
+            (Equal, Less) => Relation::Starts,
+            (Equal, Equal) => Relation::Equals,
+            (Equal, Greater) => Relation::StartedBy,
+            (Greater, Less) => Relation::During,
+            (Greater, Equal) => Relation::Finishes,
+            (Greater, Greater) => Relation::OverlappedBy,
+        }
+    }
+
+    pub fn contains(&self, point: &T) -> bool {
+        self.start <= *point && *point < self.end
+    }
+
+    /// Every point of `other` is in this interval.
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+}
+
+impl<T: Ord + Copy> Interval<T> {
+    /// Points in both intervals, `None` if there are none.
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Points in either interval, `None` when there is a gap between them.
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.end < other.start || other.end < self.start {
+            return None;
+        }
+
+        Interval::new(self.start.min(other.start), self.end.max(other.end))
+    }
+}
+
+impl<T: Ord + Copy + Sub> Interval<T> {
+    pub fn len(&self) -> T::Output {
+        self.end - self.start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn interval() -> impl Strategy<Value = Interval<i32>> {
+        (-20..20, 1..10).prop_map(|(start, len)| Interval::new(start, start + len).unwrap())
+    }
+
+    #[test]
+    fn relations() {
+        let a = Interval::new(2, 5).unwrap();
+
+        assert_eq!(a.relation(&Interval::new(6, 8).unwrap()), Relation::Before);
+        assert_eq!(a.relation(&Interval::new(5, 8).unwrap()), Relation::Meets);
+        assert_eq!(
+            a.relation(&Interval::new(4, 8).unwrap()),
+            Relation::Overlaps
+        );
+        assert_eq!(a.relation(&Interval::new(2, 8).unwrap()), Relation::Starts);
+        assert_eq!(a.relation(&Interval::new(0, 8).unwrap()), Relation::During);
+        assert_eq!(
+            a.relation(&Interval::new(0, 5).unwrap()),
+            Relation::Finishes
+        );
+        assert_eq!(a.relation(&a), Relation::Equals);
+        assert_eq!(
+            a.relation(&Interval::new(3, 4).unwrap()),
+            Relation::Contains
+        );
+        assert_eq!(a.relation(&Interval::new(0, 1).unwrap()), Relation::After);
+        assert_eq!(Interval::new(5, 5), None);
+    }
+
+    #[test]
+    fn operations() {
+        let a = Interval::new(2, 5).unwrap();
+        let b = Interval::new(4, 8).unwrap();
+        let c = Interval::new(8, 9).unwrap();
+
+        assert_eq!(a.intersection(&b), Interval::new(4, 5));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(a.union(&b), Interval::new(2, 8));
+        assert_eq!(b.union(&c), Interval::new(4, 9));
+        assert_eq!(a.union(&c), None);
+        assert_eq!(b.len(), 4);
+        assert!(a.contains(&4) && !a.contains(&5));
+        assert!(Interval::new(0, 9).unwrap().contains_interval(&b));
+    }
+
+    proptest! {
+        #[test]
+        fn exactly_one_relation_holds(a in interval(), b in interval()) {
+            let holding = Relation::ALL
+                .into_iter()
+                .filter(|relation| relation.holds(&a, &b))
+                .collect::<Vec<_>>();
+
+            prop_assert_eq!(holding, vec![a.relation(&b)]);
+        }
+
+        #[test]
+        fn relation_of_the_other_is_the_inverse(a in interval(), b in interval()) {
+            prop_assert_eq!(b.relation(&a), a.relation(&b).inverse());
+        }
+
+        #[test]
+        fn operations_agree_with_relations(a in interval(), b in interval()) {
+            let relation = a.relation(&b);
+
+            prop_assert_eq!(a.intersection(&b).is_some(), relation.is_overlap());
+            prop_assert_eq!(
+                a.contains_interval(&b) || b.contains_interval(&a),
+                relation.is_containment()
+            );
+
+            if let (Some(union), Some(intersection)) = (a.union(&b), a.intersection(&b)) {
+                prop_assert_eq!(union.len(), a.len() + b.len() - intersection.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+pub mod group;
+pub mod interval;
+pub mod optimise;
+pub mod sweep;
+
+use aoc::{PartResult, Solution};
+
+use group::Predicate;
+
+/// Counts the lines where an elf contains all the others, then the ones with any overlap.
+#[derive(Default)]
+pub struct Day04 {
+    /// Swaps inverted ranges instead of rejecting them.
+    pub normalise: bool,
+}
+
+impl Day04 {
+    fn count(&self, input: &str, predicate: Predicate) -> PartResult {
+        let groups = group::parse(input, self.normalise)?;
+
+        Ok(groups
+            .iter()
+            .filter(|g| predicate.matches(g))
+            .count()
+            .into())
+    }
+}
+
+impl Solution for Day04 {
+    fn part_1(&self, input: &str) -> PartResult {
+        self.count(input, Predicate::ContainsAll)
+    }
+
+    fn part_2(&self, input: &str) -> Option<PartResult> {
+        Some(self.count(input, Predicate::AnyOverlap))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::test_examples!(2022, 4, Day04::default());
+}
@@ -1,97 +1,128 @@
-use std::{error::Error, fmt};
-
-#[derive(Debug, PartialEq, Eq)]
-struct ParseError;
-
-impl fmt::Display for ParseError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "ParseError")
-    }
+use std::{error::Error, process};
+
+use day_04::group::{self, ElfGroup, Predicate};
+use day_04::interval::Interval;
+use day_04::optimise;
+use day_04::sweep::{self, ElfId};
+use day_04::Day04;
+
+const USAGE: &str = "Usage: day-04 [--input <path>|-] [--example <name>] [--normalise] [--count contains-all|any-overlap|all-overlap]... | [--normalise] analyze | [--normalise] diagnose | [--normalise] optimise spread|cover";
+
+enum Command {
+    /// Both 2022 answers.
+    Answer,
+    Count(Vec<Predicate>),
+    Analyze,
+    /// Every line that can't be read.
+    Diagnose,
+    /// Move elves around so each line overlaps as little as possible.
+    Spread,
+    /// Fewest elves needed to cover every assigned section.
+    Cover,
 }
 
-impl Error for ParseError {}
-
-#[derive(Debug, Clone)]
-struct Elf {
-    from: usize,
-    to: usize,
-}
-
-impl std::str::FromStr for Elf {
-    type Err = Box<dyn Error>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.split("-").collect::<Vec<_>>()[..] {
-            [from, to] => Ok(Self {
-                from: from.parse()?,
-                to: to.parse()?,
-            }),
-            _ => Err(Box::new(ParseError)),
+/// The command and whether inverted ranges are swapped instead of rejected.
+fn parse_args(args: &[String]) -> Result<(Command, bool), Box<dyn Error>> {
+    let (normalise, args) = match args {
+        [flag, rest @ ..] if flag == "--normalise" => (true, rest),
+        _ => (false, args),
+    };
+
+    match args {
+        [] => return Ok((Command::Answer, normalise)),
+        [cmd] if cmd == "analyze" => return Ok((Command::Analyze, normalise)),
+        [cmd] if cmd == "diagnose" => return Ok((Command::Diagnose, normalise)),
+        [cmd, goal] if cmd == "optimise" => {
+            return match goal.as_str() {
+                "spread" => Ok((Command::Spread, normalise)),
+                "cover" => Ok((Command::Cover, normalise)),
+                _ => Err(USAGE.into()),
+            }
         }
+        _ => {}
     }
-}
-
-#[derive(Debug, Clone)]
-struct ElfPair {
-    elf_a: Elf,
-    elf_b: Elf,
-}
 
-impl ElfPair {
-    fn is_fully_contained(&self) -> bool {
-        let ElfPair { elf_a, elf_b } = self;
-        // ...aaaaaaaaaa.....
-        // .......bbbbb......
-        let a_contains_b = elf_a.from <= elf_b.from && elf_a.to >= elf_b.to;
+    let mut predicates = vec![];
 
-        // .......aaaaaa.....
-        // .....bbbbbbbbbb...
-        let b_contains_a = elf_a.from >= elf_b.from && elf_a.to <= elf_b.to;
-
-        return a_contains_b || b_contains_a;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--count" => predicates.push(args.next().ok_or(USAGE)?.parse()?),
+            _ => return Err(USAGE.into()),
+        }
     }
 
-    fn is_overlap(&self) -> bool {
-        let ElfPair { elf_a, elf_b } = self;
-        // .........aaaaaaa..
-        // .......bbbbb......
-        let a_from = elf_a.from >= elf_b.from && elf_a.from <= elf_b.to;
-
-        // ...aaaaaaa........
-        // .......bbbbb......
-        let a_to = elf_a.to >= elf_b.from && elf_a.from <= elf_b.to;
-
-        return a_from || a_to;
-    }
+    Ok((Command::Count(predicates), normalise))
 }
 
-impl std::str::FromStr for ElfPair {
-    type Err = Box<dyn Error>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.split(",").collect::<Vec<_>>()[..] {
-            [elf_a, elf_b] => Ok(ElfPair {
-                elf_a: elf_a.parse()?,
-                elf_b: elf_b.parse()?,
-            }),
-            _ => Err(Box::new(ParseError)),
-        }
-    }
+/// Sections of every elf, one `Vec` per line.
+fn assignments(groups: &[(usize, ElfGroup)]) -> Vec<Vec<(ElfId, Interval<usize>)>> {
+    groups
+        .iter()
+        .map(|(line, group)| {
+            group
+                .elves
+                .iter()
+                .enumerate()
+                .map(|(elf, e)| (ElfId { line: *line, elf }, e.sections))
+                .collect()
+        })
+        .collect()
 }
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let input = input.trim();
-
-    let pairs = input.lines().map(|line| line.parse::<ElfPair>().unwrap());
-
-    let result_1 = pairs
-        .clone()
-        .filter(|pair| pair.is_fully_contained())
-        .count();
-
-    let result_2 = pairs.clone().filter(|pair| pair.is_overlap()).count();
+    let (input, args) = aoc::input::from_args(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let input = input.as_str();
+
+    let (command, normalise) = parse_args(&args).unwrap();
+
+    // Every group with the line it's on, or the first error
+    let groups = || {
+        group::lines(input, normalise)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_or_else(|err| {
+                eprintln!("{err}");
+                process::exit(1);
+            })
+    };
+
+    match command {
+        Command::Answer => aoc::print_answers(2022, 4, &Day04 { normalise }, input),
+        Command::Count(predicates) => {
+            let groups = groups();
+
+            for predicate in predicates {
+                let count = groups
+                    .iter()
+                    .filter(|(_, group)| predicate.matches(group))
+                    .count();
+
+                println!("{predicate}: {count}");
+            }
+        }
+        Command::Analyze => {
+            let assignments = assignments(&groups()).concat();
 
-    dbg!(result_1);
-    dbg!(result_2);
+            println!("{}", sweep::analyze(&assignments));
+        }
+        Command::Diagnose => match group::parse_all(input, normalise) {
+            Ok(_) => println!("No problems found"),
+            Err(errors) => {
+                for err in errors {
+                    println!("{err}");
+                }
+                process::exit(1);
+            }
+        },
+        Command::Spread => println!("{}", optimise::spread(&assignments(&groups()))),
+        Command::Cover => {
+            let assignments = assignments(&groups()).concat();
+            let cover = optimise::minimum_cover(&assignments);
+
+            println!("{} elves cover every assigned section", cover.len());
+            for (id, sections) in cover {
+                println!("{id}: {}-{}", sections.start(), sections.end() - 1);
+            }
+        }
+    }
 }
@@ -0,0 +1,195 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::interval::Interval;
+use crate::sweep::{sections, ElfId};
+
+/// Sections assigned to more than one elf, counted once per extra elf.
+pub fn overlap(assignments: &[Interval<usize>]) -> usize {
+    let mut sorted = assignments.to_vec();
+    sorted.sort_unstable_by_key(|sections| *sections.start());
+
+    let mut covered = 0;
+    let mut run: Option<Interval<usize>> = None;
+
+    for sections in sorted {
+        match run.and_then(|run| run.union(&sections)) {
+            Some(union) => run = Some(union),
+            None => {
+                covered += run.map_or(0, |run| run.len());
+                run = Some(sections);
+            }
+        }
+    }
+    covered += run.map_or(0, |run| run.len());
+
+    assignments.iter().map(Interval::len).sum::<usize>() - covered
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Reassignment {
+    /// Every elf with its old and new sections.
+    pub elves: Vec<(ElfId, Interval<usize>, Interval<usize>)>,
+    pub overlap_before: usize,
+    pub overlap_after: usize,
+}
+
+impl Display for Reassignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (id, before, after) in &self.elves {
+            if before != after {
+                writeln!(f, "{id}: {} -> {}", sections(before), sections(after))?;
+            }
+        }
+
+        write!(
+            f,
+            "Overlap: {} -> {} sections",
+            self.overlap_before, self.overlap_after
+        )
+    }
+}
+
+/// Moves the elves of each line so they share as few sections as possible, without changing how
+/// many sections each one has or going past the lowest and highest section in the file. Lines
+/// that can't do better are left as they are.
+pub fn spread(groups: &[Vec<(ElfId, Interval<usize>)>]) -> Reassignment {
+    let all = groups.iter().flatten();
+    let (Some(low), Some(high)) = (
+        all.clone().map(|(_, sections)| *sections.start()).min(),
+        all.map(|(_, sections)| *sections.end()).max(),
+    ) else {
+        return Reassignment::default();
+    };
+
+    let mut reassignment = Reassignment::default();
+
+    for group in groups {
+        let before = group.iter().map(|(_, s)| *s).collect::<Vec<_>>();
+        let total = before.iter().map(Interval::len).sum::<usize>();
+
+        // Packed one after the other, once past `high` they stay against it
+        let mut order = (0..group.len()).collect::<Vec<_>>();
+        order.sort_unstable_by_key(|&i| *before[i].start());
+
+        let first = order.first().map_or(low, |&i| *before[i].start());
+        let mut cursor = first.min(high.saturating_sub(total)).max(low);
+        let mut after = before.clone();
+
+        for i in order {
+            let start = cursor.min(high - before[i].len());
+            after[i] = Interval::new(start, start + before[i].len()).unwrap();
+            cursor = start + before[i].len();
+        }
+
+        let (overlap_before, mut overlap_after) = (overlap(&before), overlap(&after));
+        if overlap_after >= overlap_before {
+            after = before.clone();
+            overlap_after = overlap_before;
+        }
+
+        reassignment.overlap_before += overlap_before;
+        reassignment.overlap_after += overlap_after;
+        reassignment.elves.extend(
+            group
+                .iter()
+                .zip(after)
+                .map(|(&(id, before), after)| (id, before, after)),
+        );
+    }
+
+    reassignment
+}
+
+/// Fewest elves whose sections together are every section assigned to someone, greedily
+/// taking the elf that reaches the furthest from the first section not covered yet.
+pub fn minimum_cover(assignments: &[(ElfId, Interval<usize>)]) -> Vec<(ElfId, Interval<usize>)> {
+    let mut sorted = assignments.to_vec();
+    sorted.sort_unstable_by_key(|(id, sections)| (*sections.start(), *id));
+
+    let mut cover: Vec<(ElfId, Interval<usize>)> = vec![];
+    let mut next = 0;
+
+    while next < sorted.len() {
+        // Sections up to `covered` are done, anything starting later begins a new run
+        let covered = cover.last().map_or(0, |(_, sections)| *sections.end());
+        let from = (*sorted[next].1.start()).max(covered);
+
+        let mut best: Option<(ElfId, Interval<usize>)> = None;
+        while next < sorted.len() && *sorted[next].1.start() <= from {
+            let candidate = sorted[next];
+            if best.is_none_or(|(_, best)| candidate.1.end() > best.end()) {
+                best = Some(candidate);
+            }
+            next += 1;
+        }
+
+        match best {
+            Some(best) if *best.1.end() > covered => cover.push(best),
+            _ => {}
+        }
+    }
+
+    cover
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elf(line: usize, elf: usize, from: usize, to: usize) -> (ElfId, Interval<usize>) {
+        (ElfId { line, elf }, Interval::new(from, to + 1).unwrap())
+    }
+
+    #[test]
+    fn overlap_counts_extra_elves() {
+        let sections = |from: usize, to: usize| Interval::new(from, to + 1).unwrap();
+
+        assert_eq!(overlap(&[sections(2, 4), sections(6, 8)]), 0);
+        assert_eq!(overlap(&[sections(2, 8), sections(3, 7)]), 5);
+        assert_eq!(
+            overlap(&[sections(1, 5), sections(3, 7), sections(4, 4)]),
+            4
+        );
+    }
+
+    #[test]
+    fn spreads_each_line() {
+        let groups = vec![
+            vec![elf(1, 0, 2, 4), elf(1, 1, 6, 8)],
+            vec![elf(2, 0, 5, 7), elf(2, 1, 7, 9)],
+            vec![elf(3, 0, 2, 8), elf(3, 1, 3, 7)],
+        ];
+
+        let reassignment = spread(&groups);
+        let after = |line: usize, elf: usize| reassignment.elves[(line - 1) * 2 + elf].2;
+
+        assert_eq!(reassignment.overlap_before, 1 + 5);
+        assert_eq!(reassignment.overlap_after, 4);
+        // Already without overlap
+        assert_eq!(after(1, 0), Interval::new(2, 5).unwrap());
+        assert_eq!(after(2, 0), Interval::new(4, 7).unwrap());
+        assert_eq!(after(2, 1), Interval::new(7, 10).unwrap());
+        // Too long to fit apart, they overlap as little as possible
+        assert_eq!(after(3, 0), Interval::new(2, 9).unwrap());
+        assert_eq!(after(3, 1), Interval::new(5, 10).unwrap());
+    }
+
+    #[test]
+    fn fewest_elves_covering_everything() {
+        let assignments = vec![
+            elf(1, 0, 1, 3),
+            elf(1, 1, 2, 6),
+            elf(2, 0, 4, 5),
+            elf(2, 1, 5, 8),
+            elf(3, 0, 12, 14),
+            elf(3, 1, 13, 13),
+        ];
+
+        let cover = minimum_cover(&assignments)
+            .into_iter()
+            .map(|(id, _)| (id.line, id.elf))
+            .collect::<Vec<_>>();
+
+        assert_eq!(cover, vec![(1, 0), (1, 1), (2, 1), (3, 0)]);
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::BTreeSet;
+use std::fmt::{self, Display, Formatter};
+
+use crate::interval::Interval;
+
+/// Which elf of the file, `line` starts at 1 and `elf` is the position in the line from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ElfId {
+    pub line: usize,
+    pub elf: usize,
+}
+
+impl Display for ElfId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} elf {}", self.line, self.elf + 1)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Coverage {
+    /// Sections assigned to at least one elf.
+    pub covered: usize,
+    /// Runs of sections nobody is assigned to between the lowest and highest section.
+    pub gaps: Vec<Interval<usize>>,
+    /// Most elves assigned to the same section.
+    pub max_elves: usize,
+    /// Runs of sections with `max_elves` elves assigned.
+    pub busiest: Vec<Interval<usize>>,
+    /// Elves on different lines with sections in common, each pair sorted and in order.
+    pub overlaps: Vec<(ElfId, ElfId)>,
+}
+
+impl Coverage {
+    pub fn uncovered(&self) -> usize {
+        self.gaps.iter().map(Interval::len).sum()
+    }
+}
+
+/// Sections as written in the input, `from-to` with both ends included.
+pub(crate) fn sections(interval: &Interval<usize>) -> String {
+    let (from, to) = (interval.start(), interval.end() - 1);
+
+    match *from == to {
+        true => format!("{from}"),
+        false => format!("{from}-{to}"),
+    }
+}
+
+fn list(intervals: &[Interval<usize>]) -> String {
+    if intervals.is_empty() {
+        return String::new();
+    }
+
+    let list = intervals
+        .iter()
+        .map(sections)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(" ({list})")
+}
+
+impl Display for Coverage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Sections covered: {}", self.covered)?;
+        writeln!(
+            f,
+            "Sections not covered: {}{}",
+            self.uncovered(),
+            list(&self.gaps)
+        )?;
+        writeln!(
+            f,
+            "Most elves on a section: {}{}",
+            self.max_elves,
+            list(&self.busiest)
+        )?;
+        write!(
+            f,
+            "Overlapping elves on different lines: {}",
+            self.overlaps.len()
+        )?;
+
+        for (a, b) in &self.overlaps {
+            write!(f, "\n  {a} - {b}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds `interval` to `intervals`, merging it with the last one when they touch.
+fn push_run(intervals: &mut Vec<Interval<usize>>, interval: Interval<usize>) {
+    match intervals.last_mut() {
+        Some(last) if last.end() == interval.start() => *last = last.union(&interval).unwrap(),
+        _ => intervals.push(interval),
+    }
+}
+
+/// Sweeps over the start and end of every assignment in `O(n log n)`, plus the amount of
+/// overlapping pairs found.
+pub fn analyze(assignments: &[(ElfId, Interval<usize>)]) -> Coverage {
+    let mut coverage = Coverage::default();
+
+    // Ends sort before starts at the same point, intervals are half-open
+    let mut events = assignments
+        .iter()
+        .flat_map(|(_, sections)| [(*sections.start(), 1), (*sections.end(), -1)])
+        .collect::<Vec<(usize, i32)>>();
+    events.sort_unstable_by_key(|&(point, change)| (point, change));
+
+    let mut elves = 0;
+
+    for window in events.windows(2) {
+        let [(point, change), (next, _)] = [window[0], window[1]];
+        elves = (elves as i32 + change) as usize;
+
+        let Some(run) = Interval::new(point, next) else {
+            continue;
+        };
+
+        match elves {
+            0 => push_run(&mut coverage.gaps, run),
+            _ => coverage.covered += run.len(),
+        }
+
+        if elves > coverage.max_elves {
+            coverage.max_elves = elves;
+            coverage.busiest.clear();
+        }
+
+        if elves == coverage.max_elves && elves > 0 {
+            push_run(&mut coverage.busiest, run);
+        }
+    }
+
+    // Every assignment still active when another one starts overlaps it
+    let mut by_start = assignments.iter().collect::<Vec<_>>();
+    by_start.sort_unstable_by_key(|(id, sections)| (*sections.start(), *id));
+
+    let mut active = BTreeSet::<(usize, ElfId)>::new();
+
+    for &(id, sections) in by_start {
+        while active
+            .first()
+            .is_some_and(|&(end, _)| end <= *sections.start())
+        {
+            active.pop_first();
+        }
+
+        for &(_, other) in &active {
+            if other.line != id.line {
+                coverage.overlaps.push((other.min(id), other.max(id)));
+            }
+        }
+
+        active.insert((*sections.end(), id));
+    }
+
+    coverage.overlaps.sort_unstable();
+
+    coverage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: [[(usize, usize); 2]; 6] = [
+        [(2, 4), (6, 8)],
+        [(2, 3), (4, 5)],
+        [(5, 7), (7, 9)],
+        [(2, 8), (3, 7)],
+        [(6, 6), (4, 6)],
+        [(2, 6), (4, 8)],
+    ];
+
+    fn assignments(input: &[[(usize, usize); 2]]) -> Vec<(ElfId, Interval<usize>)> {
+        input
+            .iter()
+            .enumerate()
+            .flat_map(|(line, pair)| {
+                pair.iter().enumerate().map(move |(elf, &(from, to))| {
+                    let id = ElfId {
+                        line: line + 1,
+                        elf,
+                    };
+
+                    (id, Interval::new(from, to + 1).unwrap())
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn coverage() {
+        let coverage = analyze(&assignments(&INPUT));
+
+        assert_eq!(coverage.covered, 8);
+        assert_eq!(coverage.gaps, vec![]);
+        assert_eq!(coverage.max_elves, 8);
+        assert_eq!(coverage.busiest, vec![Interval::new(6, 7).unwrap()]);
+
+        let coverage = analyze(&assignments(&[[(1, 2), (5, 5)], [(8, 9), (2, 3)]]));
+
+        assert_eq!(coverage.covered, 6);
+        assert_eq!(
+            coverage.gaps,
+            vec![Interval::new(4, 5).unwrap(), Interval::new(6, 8).unwrap()]
+        );
+        assert_eq!(coverage.max_elves, 2);
+        assert_eq!(coverage.uncovered(), 3);
+    }
+
+    #[test]
+    fn overlaps_match_checking_every_pair() {
+        let assignments = assignments(&INPUT);
+
+        let mut expected = vec![];
+        for (i, (a, a_sections)) in assignments.iter().enumerate() {
+            for (b, b_sections) in &assignments[i + 1..] {
+                if a.line != b.line && a_sections.intersection(b_sections).is_some() {
+                    expected.push((*a, *b));
+                }
+            }
+        }
+        expected.sort_unstable();
+
+        assert_eq!(analyze(&assignments).overlaps, expected);
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use aoc::{PartResult, Solution};
+
+#[derive(Clone, Debug)]
+struct CrateId {
+    id: char,
+}
+
+impl CrateId {
+    fn from_chars(chars: Vec<char>) -> Option<Self> {
+        match chars.get(1) {
+            None => None,
+            Some(id) if id.is_whitespace() => None,
+            Some(id) => Some(CrateId { id: *id }),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Operation {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+impl From<&str> for Operation {
+    fn from(s: &str) -> Self {
+        let parts = s.split_whitespace().collect::<Vec<_>>();
+        let amount = parts.get(1).unwrap().parse().unwrap();
+        let from = parts.get(3).unwrap().parse().unwrap();
+        let to = parts.get(5).unwrap().parse().unwrap();
+
+        Operation { amount, from, to }
+    }
+}
+
+type Crates = HashMap<usize, Vec<CrateId>>;
+
+fn operate_with_9000(crates: &mut Crates, operation: &Operation) {
+    for _ in 0..operation.amount {
+        let crate_id = crates.get_mut(&operation.from).unwrap().pop().unwrap();
+        crates.get_mut(&operation.to).unwrap().push(crate_id);
+    }
+}
+
+fn operate_with_9001(crates: &mut Crates, operation: &Operation) {
+    let mut batch = {
+        let from = crates.get_mut(&operation.from).unwrap();
+        from.drain(from.len() - operation.amount..)
+            .collect::<Vec<_>>()
+    };
+
+    crates.get_mut(&operation.to).unwrap().append(&mut batch);
+}
+
+fn get_result(crates: Crates) -> String {
+    let mut result = crates
+        .into_iter()
+        .map(|(stack_position, mut stack)| (stack_position, stack.pop().unwrap()))
+        .collect::<Vec<_>>();
+
+    result.sort_by_key(|(stack_position, _)| *stack_position);
+
+    result
+        .into_iter()
+        .map(|(_, crate_id)| crate_id.id)
+        .collect::<String>()
+}
+
+fn parse_crates(stacks: &str) -> Crates {
+    let mut crates = Crates::new();
+    stacks
+        .lines()
+        .rev()
+        .skip(1)
+        .map(String::from)
+        .for_each(|line| {
+            line.chars()
+                .collect::<Vec<_>>()
+                .chunks(4)
+                .map(|chunk| CrateId::from_chars(chunk.to_vec()))
+                .enumerate()
+                .for_each(|(index, crated_id)| {
+                    if let Some(crate_id) = crated_id {
+                        crates.entry(index + 1).or_default().push(crate_id);
+                    }
+                });
+        });
+
+    crates
+}
+
+/// Crates on top of every stack once the crane ran every instruction.
+fn rearrange(input: &str, operate: fn(&mut Crates, &Operation)) -> String {
+    let (stacks, instructions) = input.split_once("\n\n").unwrap();
+
+    let mut crates = parse_crates(stacks);
+
+    instructions.lines().for_each(|line| {
+        let operation = Operation::from(line);
+
+        operate(&mut crates, &operation);
+    });
+
+    get_result(crates)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn part_1(&self, input: &str) -> PartResult {
+        Ok(rearrange(input, operate_with_9000).into())
+    }
+
+    fn part_2(&self, input: &str) -> Option<PartResult> {
+        Some(Ok(rearrange(input, operate_with_9001).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::test_examples!(2022, 5, Day05);
+}
@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::marker;
+
+#[derive(Debug, Serialize)]
+pub struct Substring {
+    start: usize,
+    length: usize,
+    value: String,
+}
+
+/// Everything we know about a datastream for a given window size.
+#[derive(Debug, Serialize)]
+pub struct Analysis {
+    window: usize,
+    markers: Vec<usize>,
+    longest_distinct: Substring,
+    /// Amount of windows per count of distinct characters in them.
+    histogram: BTreeMap<usize, usize>,
+}
+
+impl Analysis {
+    pub fn new(stream: &[char], window: usize) -> Self {
+        let markers = marker::windows(stream, window).markers().collect();
+
+        let (start, length) = marker::longest_distinct(stream);
+        let longest_distinct = Substring {
+            start,
+            length,
+            value: stream[start..start + length].iter().collect(),
+        };
+
+        let mut histogram = BTreeMap::new();
+        for (_, distinct) in marker::windows(stream, window) {
+            *histogram.entry(distinct).or_default() += 1;
+        }
+
+        Analysis {
+            window,
+            markers,
+            longest_distinct,
+            histogram,
+        }
+    }
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let markers = self
+            .markers
+            .iter()
+            .map(|position| position.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        writeln!(f, "Window size:      {}", self.window)?;
+        writeln!(f, "Marker count:     {}", self.markers.len())?;
+        writeln!(f, "Marker positions: {}", markers)?;
+        writeln!(
+            f,
+            "Longest distinct: {:?} (start {}, length {})",
+            self.longest_distinct.value, self.longest_distinct.start, self.longest_distinct.length
+        )?;
+        writeln!(f)?;
+        writeln!(f, "{:>8} | {:>8}", "distinct", "windows")?;
+        writeln!(f, "{:-<8}-+-{:-<8}", "", "")?;
+
+        for (distinct, windows) in &self.histogram {
+            writeln!(f, "{:>8} | {:>8}", distinct, windows)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format {s:?}").into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analysis(stream: &str, window: usize) -> Analysis {
+        Analysis::new(&stream.chars().collect::<Vec<_>>(), window)
+    }
+
+    #[test]
+    fn markers_of_the_examples() {
+        let packet = analysis("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4);
+        assert_eq!(packet.markers[0], 7);
+        assert_eq!(packet.markers.len(), 24);
+        assert_eq!(*packet.markers.last().unwrap(), 30);
+
+        // The four characters before position 11, `bgvb`, have `b` twice
+        let packet = analysis("bvwbjplbgvbhsrlpgdmjqwftvncz", 4);
+        assert_eq!(packet.markers[..6], [5, 6, 7, 8, 9, 10]);
+        assert_eq!(packet.markers[6], 12);
+
+        assert_eq!(
+            analysis("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14).markers[0],
+            19
+        );
+        assert_eq!(analysis("bvwbjplbgvbhsrlpgdmjqwftvncz", 14).markers[0], 23);
+        assert!(analysis("abcabc", 4).markers.is_empty());
+    }
+
+    #[test]
+    fn longest_run_of_distinct_characters() {
+        let longest = analysis("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4).longest_distinct;
+        assert_eq!(
+            (longest.start, longest.length, longest.value.as_str()),
+            (12, 18, "phdztnvjfqwrcgsmlb")
+        );
+
+        let longest = analysis("bvwbjplbgvbhsrlpgdmjqwftvncz", 4).longest_distinct;
+        assert_eq!(
+            (longest.start, longest.length, longest.value.as_str()),
+            (10, 18, "bhsrlpgdmjqwftvncz")
+        );
+    }
+
+    #[test]
+    fn histogram_counts_every_window() {
+        let stream = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let histogram = analysis(stream, 4).histogram;
+
+        assert_eq!(histogram, BTreeMap::from([(3, 3), (4, 24)]));
+        assert_eq!(histogram.values().sum::<usize>(), stream.len() - 4 + 1);
+        assert_eq!(analysis("aaaa", 2).histogram, BTreeMap::from([(1, 3)]));
+    }
+}
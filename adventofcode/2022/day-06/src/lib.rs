@@ -0,0 +1,31 @@
+pub mod analysis;
+pub mod marker;
+
+use aoc::{PartResult, Solution};
+
+fn marker(input: &str, size: usize) -> PartResult {
+    let position = marker::find_marker_in_str(input, size)
+        .ok_or(format!("no {size} different characters in a row"))?;
+
+    Ok(position.into())
+}
+
+/// Start of the first packet, then of the first message.
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn part_1(&self, input: &str) -> PartResult {
+        marker(input, 4)
+    }
+
+    fn part_2(&self, input: &str) -> Option<PartResult> {
+        Some(marker(input, 14))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::test_examples!(2022, 6, Day06);
+}
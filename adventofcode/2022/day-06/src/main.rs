@@ -1,34 +1,46 @@
-use std::collections::HashSet;
 use std::error::Error;
-use std::io;
-use std::io::Read;
 
-fn read_stdin() -> Result<String, Box<dyn Error>> {
-    let mut buf: Vec<u8> = vec![];
+use day_06::analysis::{Analysis, Format};
+use day_06::{marker, Day06};
 
-    io::stdin().read_to_end(&mut buf)?;
+/// `day-06 [--input <path>|-] [--example <name>] analyze [--window <size>] [--format table|json]`
+fn analyze(stream: &[char], args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut window = 4;
+    let mut format = Format::Table;
 
-    Ok(String::from_utf8(buf)?)
-}
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for {arg}"))?;
 
-fn main() {
-    let input = read_stdin().unwrap();
+        match arg.as_str() {
+            "--window" => window = value.parse()?,
+            "--format" => format = value.parse()?,
+            _ => return Err(format!("Unknown argument {arg}").into()),
+        }
+    }
 
-    let (result, _) = input
-        .chars()
-        .collect::<Vec<char>>()
-        .windows(4)
-        .enumerate()
-        .find(|(_, window)| window.into_iter().collect::<HashSet<_>>().len() == 4).unwrap();
+    let analysis = Analysis::new(stream, window);
 
-    println!("Start of packet marker: {}", result + 4);
+    match format {
+        Format::Table => print!("{analysis}"),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&analysis)?),
+    }
 
-    let (result, _) = input
-        .chars()
-        .collect::<Vec<char>>()
-        .windows(14)
-        .enumerate()
-        .find(|(_, window)| window.into_iter().collect::<HashSet<_>>().len() == 14).unwrap();
+    Ok(())
+}
 
-    println!("Start of message marker: {}", result + 14);
+fn main() {
+    let (input, args) = aoc::input::from_args(env!("CARGO_MANIFEST_DIR")).unwrap();
+
+    match args.first().map(String::as_str) {
+        Some("analyze") => {
+            let stream = marker::trim_line_breaks(&input)
+                .chars()
+                .collect::<Vec<char>>();
+
+            analyze(&stream, &args[1..]).unwrap();
+        }
+        Some(cmd) => panic!("Unknown command {cmd}"),
+        None => aoc::print_answers(2022, 6, &Day06, &input),
+    }
 }
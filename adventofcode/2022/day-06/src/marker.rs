@@ -0,0 +1,273 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Keeps track of how many times each token is inside a window.
+pub trait Counter<T> {
+    fn push(&mut self, token: &T);
+    fn pop(&mut self, token: &T);
+    fn count(&self, token: &T) -> usize;
+    fn distinct(&self) -> usize;
+}
+
+/// Counter for any alphabet whose tokens can be hashed.
+#[derive(Debug)]
+pub struct HashCounter<T> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T> Default for HashCounter<T> {
+    fn default() -> Self {
+        HashCounter {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> Counter<T> for HashCounter<T> {
+    fn push(&mut self, token: &T) {
+        *self.counts.entry(token.clone()).or_default() += 1;
+    }
+
+    fn pop(&mut self, token: &T) {
+        if let Some(count) = self.counts.get_mut(token) {
+            *count -= 1;
+
+            if *count == 0 {
+                self.counts.remove(token);
+            }
+        }
+    }
+
+    fn count(&self, token: &T) -> usize {
+        self.counts.get(token).copied().unwrap_or_default()
+    }
+
+    fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+}
+
+/// Counter for `a-z` bytes (the puzzle alphabet) that avoids hashing. Any other byte panics.
+#[derive(Debug, Default)]
+pub struct LowercaseCounter {
+    counts: [usize; 26],
+    distinct: usize,
+}
+
+impl Counter<u8> for LowercaseCounter {
+    fn push(&mut self, token: &u8) {
+        let count = &mut self.counts[(token - b'a') as usize];
+        if *count == 0 {
+            self.distinct += 1;
+        }
+
+        *count += 1;
+    }
+
+    fn pop(&mut self, token: &u8) {
+        let count = &mut self.counts[(token - b'a') as usize];
+        if *count == 1 {
+            self.distinct -= 1;
+        }
+
+        *count = count.saturating_sub(1);
+    }
+
+    fn count(&self, token: &u8) -> usize {
+        self.counts[(token - b'a') as usize]
+    }
+
+    fn distinct(&self) -> usize {
+        self.distinct
+    }
+}
+
+/// Slides a window of `size` over the datastream one token at a time.
+///
+/// Yields `(position, distinct)` where `position` is the number of tokens processed when the
+/// window ends (the same number the puzzle asks for) and `distinct` is the amount of different
+/// tokens inside the window.
+pub struct Windows<I: Iterator, C> {
+    tokens: I,
+    size: usize,
+    position: usize,
+    window: VecDeque<I::Item>,
+    counter: C,
+}
+
+impl<I, C> Windows<I, C>
+where
+    I: Iterator,
+    C: Counter<I::Item>,
+{
+    pub fn new<T>(tokens: T, size: usize, counter: C) -> Self
+    where
+        T: IntoIterator<IntoIter = I>,
+    {
+        Windows {
+            tokens: tokens.into_iter(),
+            size,
+            position: 0,
+            window: VecDeque::with_capacity(size + 1),
+            counter,
+        }
+    }
+
+    /// Every position where the last `size` tokens are all different.
+    pub fn markers(self) -> impl Iterator<Item = usize> {
+        let size = self.size;
+
+        self.filter(move |(_, distinct)| *distinct == size)
+            .map(|(position, _)| position)
+    }
+}
+
+impl<I, C> Iterator for Windows<I, C>
+where
+    I: Iterator,
+    C: Counter<I::Item>,
+{
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.size == 0 {
+            return None;
+        }
+
+        for token in self.tokens.by_ref() {
+            self.counter.push(&token);
+            self.window.push_back(token);
+            self.position += 1;
+
+            if self.window.len() > self.size {
+                let token = self.window.pop_front().unwrap();
+                self.counter.pop(&token);
+            }
+
+            if self.window.len() == self.size {
+                return Some((self.position, self.counter.distinct()));
+            }
+        }
+
+        None
+    }
+}
+
+/// Windows over any alphabet.
+pub fn windows<T>(tokens: T, size: usize) -> Windows<T::IntoIter, HashCounter<T::Item>>
+where
+    T: IntoIterator,
+    T::Item: Eq + Hash + Clone,
+{
+    Windows::new(tokens, size, HashCounter::default())
+}
+
+pub fn find_marker<T>(tokens: T, size: usize) -> Option<usize>
+where
+    T: IntoIterator,
+    T::Item: Eq + Hash + Clone,
+{
+    windows(tokens, size).markers().next()
+}
+
+/// Drops the line breaks a datastream read from a file usually ends with, they are not part of
+/// the signal.
+pub fn trim_line_breaks(datastream: &str) -> &str {
+    datastream.trim_end_matches(['\n', '\r'])
+}
+
+/// Finds the marker in a text datastream, counting positions in `char`s. ASCII lowercase streams
+/// (what the puzzle gives us) take the fast path, anything else falls back to hashing.
+pub fn find_marker_in_str(datastream: &str, size: usize) -> Option<usize> {
+    let datastream = trim_line_breaks(datastream);
+
+    if datastream.bytes().all(|b| b.is_ascii_lowercase()) {
+        Windows::new(datastream.bytes(), size, LowercaseCounter::default())
+            .markers()
+            .next()
+    } else {
+        find_marker(datastream.chars(), size)
+    }
+}
+
+/// Start offset and length of the longest run of tokens that are all different. When there are
+/// several runs of the same length the first one wins.
+pub fn longest_distinct<T>(tokens: T) -> (usize, usize)
+where
+    T: IntoIterator,
+    T::Item: Eq + Hash + Clone,
+{
+    let mut counter = HashCounter::default();
+    let mut window = VecDeque::new();
+    let mut start = 0;
+    let mut longest = (0, 0);
+
+    for token in tokens {
+        counter.push(&token);
+
+        // Shrink the window from the left until `token` is unique again
+        while counter.count(&token) > 1 {
+            let first = window.pop_front().unwrap();
+            counter.pop(&first);
+            start += 1;
+        }
+
+        window.push_back(token);
+
+        if window.len() > longest.1 {
+            longest = (start, window.len());
+        }
+    }
+
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle_examples() {
+        let examples = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+
+        for (datastream, packet, message) in examples {
+            assert_eq!(find_marker_in_str(datastream, 4), Some(packet));
+            assert_eq!(find_marker_in_str(datastream, 14), Some(message));
+            assert_eq!(find_marker(datastream.chars(), 4), Some(packet));
+        }
+    }
+
+    #[test]
+    fn trailing_line_breaks_are_not_part_of_the_stream() {
+        assert_eq!(find_marker_in_str("abca\n", 4), None);
+        assert_eq!(find_marker_in_str("abca\r\n", 4), None);
+        assert_eq!(find_marker_in_str("abcd\n\n", 4), Some(4));
+    }
+
+    #[test]
+    fn unicode_positions_are_counted_in_chars() {
+        assert_eq!(find_marker_in_str("ααβγδ", 4), Some(5));
+        assert_eq!(find_marker_in_str("ñaña🎄b", 3), Some(5));
+        assert_eq!(find_marker_in_str("aAbB", 4), Some(4));
+    }
+
+    #[test]
+    fn other_alphabets() {
+        assert_eq!(find_marker(b"\x00\x00\xff\x01".iter(), 3), Some(4));
+        assert_eq!(find_marker("up up down left".split(' '), 3), Some(4));
+        assert_eq!(find_marker([1, 2, 1, 2], 3), None);
+    }
+
+    #[test]
+    fn longest_distinct_run() {
+        assert_eq!(longest_distinct("abcabcbb".chars()), (0, 3));
+        assert_eq!(longest_distinct("pwwkew".chars()), (2, 3));
+        assert_eq!(longest_distinct("".chars()), (0, 0));
+    }
+}
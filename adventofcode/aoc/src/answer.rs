@@ -0,0 +1,60 @@
+use std::fmt::{self, Display, Formatter};
+
+/// What a part of a puzzle gives back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Wide enough for every signed and unsigned integer up to 64 bits.
+    Integer(i128),
+    Text(String),
+    /// Answers drawn as several lines, like letters on a screen.
+    Lines(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{n}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n.into())
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i32, i64, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        // No target has a `usize` wider than 64 bits
+        Answer::Integer(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Self {
+        Answer::Lines(lines)
+    }
+}
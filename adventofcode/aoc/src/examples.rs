@@ -0,0 +1,202 @@
+//! Named example inputs of a day, in its `examples` directory. `<name>.txt` is the input,
+//! `<name>.part1` and `<name>.part2` the expected answers, either can be left out.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+use crate::input::{normalise, Source};
+use crate::{solve, Outcome, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    /// Expected answers, written the way `Answer` displays them.
+    pub expected: [Option<String>; 2],
+}
+
+impl Example {
+    /// The example `name` of the day whose crate is in `dir`.
+    pub fn load(dir: &Path, name: &str) -> Result<Self, Box<dyn Error>> {
+        let input = Source::Example(name.to_string()).read(dir)?;
+        let expected = [1, 2].map(|part| {
+            let path = dir.join("examples").join(format!("{name}.part{part}"));
+            fs::read_to_string(path)
+                .ok()
+                .map(|answer| normalise(&answer).trim_end().to_string())
+        });
+
+        Ok(Example {
+            name: name.to_string(),
+            input,
+            expected,
+        })
+    }
+
+    /// Compares what `outcomes` answered with what is expected of this example.
+    pub fn check(&self, outcomes: &[Outcome]) -> Vec<Mismatch> {
+        let mut mismatches = vec![];
+
+        for (part, expected) in (1..).zip(&self.expected) {
+            let outcome = outcomes.iter().find(|outcome| outcome.part == part);
+            let actual = match outcome.map(|outcome| &outcome.result) {
+                Some(Ok(answer)) => Some(Ok(answer.to_string())),
+                Some(Err(err)) => Some(Err(err.clone())),
+                None => None,
+            };
+
+            let matches = match (expected, &actual) {
+                (Some(expected), Some(Ok(actual))) => expected == actual,
+                (Some(_), _) => false,
+                // Unchecked, but the part must not fail
+                (None, actual) => !matches!(actual, Some(Err(_))),
+            };
+
+            if !matches {
+                mismatches.push(Mismatch {
+                    example: self.name.clone(),
+                    part,
+                    expected: expected.clone(),
+                    actual,
+                });
+            }
+        }
+
+        mismatches
+    }
+}
+
+/// Every example of the day whose crate is in `dir`, sorted by name.
+pub fn all(dir: &Path) -> Result<Vec<Example>, Box<dyn Error>> {
+    let examples = dir.join("examples");
+    if !examples.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let mut names = vec![];
+    for entry in fs::read_dir(&examples)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            let name = path.file_stem().ok_or("example without a name")?;
+            names.push(name.to_string_lossy().into_owned());
+        }
+    }
+    names.sort();
+
+    names.iter().map(|name| Example::load(dir, name)).collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub example: String,
+    pub part: u32,
+    pub expected: Option<String>,
+    /// `None` when the part isn't solved.
+    pub actual: Option<Result<String, String>>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "example {} part {}: ", self.example, self.part)?;
+
+        match (&self.expected, &self.actual) {
+            (_, Some(Err(err))) => write!(f, "error: {err}"),
+            (Some(expected), Some(Ok(actual))) => {
+                write!(f, "expected {expected:?}, got {actual:?}")
+            }
+            (Some(expected), None) => write!(f, "expected {expected:?}, the part isn't solved"),
+            (None, _) => write!(f, "nothing expected"),
+        }
+    }
+}
+
+/// Runs `solution`, the one of `year` `day` whose crate is in `dir`, on every example of the day,
+/// panicking with every answer that isn't the expected one. A day without examples yet has
+/// nothing to check.
+pub fn assert_examples(dir: &str, year: u32, day: u32, solution: &dyn Solution) {
+    let examples = all(Path::new(dir)).unwrap();
+    if examples.is_empty() {
+        eprintln!("no examples in {dir}/examples, nothing checked");
+        return;
+    }
+
+    let mismatches = examples
+        .iter()
+        .flat_map(|example| example.check(&solve(year, day, solution, &example.input)))
+        .map(|mismatch| format!("{year} day {day} {mismatch}"))
+        .collect::<Vec<_>>();
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+/// A test running the solution of `year` `day` on every example of the crate it's used in.
+#[macro_export]
+macro_rules! test_examples {
+    ($year:expr, $day:expr, $solution:expr) => {
+        #[test]
+        fn examples() {
+            $crate::examples::assert_examples(env!("CARGO_MANIFEST_DIR"), $year, $day, &$solution);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Answer;
+
+    fn outcome(part: u32, result: Result<Answer, String>) -> Outcome {
+        Outcome {
+            year: 2022,
+            day: 6,
+            part,
+            result,
+            duration: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn compares_expected_answers() {
+        let example = Example {
+            name: "first".to_string(),
+            input: String::new(),
+            expected: [Some("7".to_string()), None],
+        };
+
+        assert!(example
+            .check(&[outcome(1, Ok(7.into())), outcome(2, Ok(19.into()))])
+            .is_empty());
+        assert!(example.check(&[outcome(1, Ok(7.into()))]).is_empty());
+
+        let mismatches = example.check(&[outcome(1, Ok(8.into())), outcome(2, Err("x".into()))]);
+        assert_eq!(
+            mismatches
+                .iter()
+                .map(|mismatch| mismatch.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "example first part 1: expected \"7\", got \"8\"",
+                "example first part 2: error: x",
+            ]
+        );
+    }
+
+    #[test]
+    fn unsolved_parts_fail_when_expected() {
+        let example = Example {
+            name: "first".to_string(),
+            input: String::new(),
+            expected: [None, Some("CMZ".to_string())],
+        };
+
+        let mismatches = example.check(&[outcome(1, Ok(21.into()))]);
+        assert_eq!(
+            mismatches[0].to_string(),
+            "example first part 2: expected \"CMZ\", the part isn't solved"
+        );
+    }
+}
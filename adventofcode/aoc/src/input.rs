@@ -0,0 +1,125 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where the puzzle input of a day comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `input.txt` next to the day's `Cargo.toml`, as saved by `get-puzzel-input.sh`.
+    Cached,
+    Path(PathBuf),
+    Stdin,
+    /// `examples/<name>.txt` next to the day's `Cargo.toml`.
+    Example(String),
+}
+
+impl Source {
+    /// Takes `--input <path>` (`-` for stdin) and `--example <name>` out of `args`, the input is
+    /// the cached one when neither is given.
+    pub fn extract(args: Vec<String>) -> Result<(Source, Vec<String>), Box<dyn Error>> {
+        let mut source = None;
+        let mut rest = vec![];
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let selected = match arg.as_str() {
+                "--input" => match args.next().ok_or("Missing path for --input")? {
+                    path if path == "-" => Source::Stdin,
+                    path => Source::Path(path.into()),
+                },
+                "--example" => Source::Example(args.next().ok_or("Missing name for --example")?),
+                _ => {
+                    rest.push(arg);
+                    continue;
+                }
+            };
+
+            if source.replace(selected).is_some() {
+                return Err("Only one of --input and --example can be given".into());
+            }
+        }
+
+        Ok((source.unwrap_or(Source::Cached), rest))
+    }
+
+    /// Reads the input of the day whose crate is in `dir`, normalised.
+    pub fn read(&self, dir: &Path) -> Result<String, Box<dyn Error>> {
+        let read = |path: PathBuf| {
+            fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))
+        };
+
+        let input = match self {
+            Source::Cached => read(dir.join("input.txt"))
+                .map_err(|err| format!("{err}, fetch the puzzle input with get-puzzel-input.sh"))?,
+            Source::Path(path) => read(path.clone())?,
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            Source::Example(name) => read(dir.join("examples").join(format!("{name}.txt")))?,
+        };
+
+        Ok(normalise(&input))
+    }
+}
+
+/// Turns `\r\n` and lone `\r` into `\n` and leaves exactly one line break at the end.
+pub fn normalise(input: &str) -> String {
+    let mut input = input.replace("\r\n", "\n").replace('\r', "\n");
+
+    input.truncate(input.trim_end_matches('\n').len());
+    if !input.is_empty() {
+        input.push('\n');
+    }
+
+    input
+}
+
+/// Input of the day whose crate is in `dir` and the command line arguments left once the input
+/// ones are taken out.
+pub fn from_args(dir: &str) -> Result<(String, Vec<String>), Box<dyn Error>> {
+    let (source, args) = Source::extract(env::args().skip(1).collect())?;
+
+    Ok((source.read(Path::new(dir))?, args))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn line_endings_and_trailing_newlines() {
+        assert_eq!(normalise("a\r\nb\rc\n\n\n"), "a\nb\nc\n");
+        assert_eq!(normalise("a\n\nb"), "a\n\nb\n");
+        assert_eq!(normalise("\n\n"), "");
+    }
+
+    #[test]
+    fn input_arguments_are_taken_out() {
+        assert_eq!(
+            Source::extract(args("stats --format csv")).unwrap(),
+            (Source::Cached, args("stats --format csv"))
+        );
+        assert_eq!(
+            Source::extract(args("--top 2 --input other.txt")).unwrap(),
+            (Source::Path("other.txt".into()), args("--top 2"))
+        );
+        assert_eq!(
+            Source::extract(args("--input - analyze")).unwrap(),
+            (Source::Stdin, args("analyze"))
+        );
+        assert_eq!(
+            Source::extract(args("--example larger")).unwrap(),
+            (Source::Example("larger".to_string()), vec![])
+        );
+        assert!(Source::extract(args("--input a.txt --example b")).is_err());
+        assert!(Source::extract(args("--input")).is_err());
+    }
+}
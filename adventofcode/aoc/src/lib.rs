@@ -0,0 +1,158 @@
+//! Pieces shared by every day: where the input comes from, the examples it is checked against,
+//! the answers a puzzle gives and how they get printed.
+
+mod answer;
+pub mod examples;
+pub mod input;
+pub mod output;
+
+use std::any::Any;
+use std::cell::Cell;
+use std::error::Error;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+pub use answer::Answer;
+use output::Format;
+
+pub type PartResult = Result<Answer, Box<dyn Error>>;
+
+/// `Sync` so the runner can solve several days at once.
+pub trait Solution: Sync {
+    fn part_1(&self, input: &str) -> PartResult;
+
+    /// `None` until the second part is solved.
+    fn part_2(&self, input: &str) -> Option<PartResult>;
+}
+
+/// The answer of one part, or why there is none, and how long it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub year: u32,
+    pub day: u32,
+    /// 1 or 2.
+    pub part: u32,
+    pub result: Result<Answer, String>,
+    pub duration: Duration,
+}
+
+thread_local! {
+    /// Set while a part runs, its panics become the outcome instead of being printed.
+    static SOLVING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the panic hook, once, so it stays quiet about panics of a part.
+fn quiet_panics_of_parts() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SOLVING.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+fn timed<F>(year: u32, day: u32, part: u32, f: F) -> Option<Outcome>
+where
+    F: FnOnce() -> Option<PartResult>,
+{
+    quiet_panics_of_parts();
+
+    let start = Instant::now();
+    SOLVING.set(true);
+    let caught = panic::catch_unwind(AssertUnwindSafe(f));
+    SOLVING.set(false);
+
+    let result = match caught {
+        Ok(result) => result?.map_err(|err| err.to_string()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+    };
+
+    Some(Outcome {
+        year,
+        day,
+        part,
+        result,
+        duration: start.elapsed(),
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message,
+        (_, Some(message)) => message,
+        _ => "no message",
+    }
+}
+
+/// Runs `part` of `solution` on `input`, `None` when that part isn't solved. A part that panics
+/// fails.
+pub fn solve_part(
+    year: u32,
+    day: u32,
+    part: u32,
+    solution: &dyn Solution,
+    input: &str,
+) -> Option<Outcome> {
+    match part {
+        1 => timed(year, day, part, || Some(solution.part_1(input))),
+        2 => timed(year, day, part, || solution.part_2(input)),
+        _ => None,
+    }
+}
+
+/// Runs every solved part of `solution` on `input`, a part that panics fails without stopping
+/// the other.
+pub fn solve(year: u32, day: u32, solution: &dyn Solution, input: &str) -> Vec<Outcome> {
+    [1, 2]
+        .into_iter()
+        .filter_map(|part| solve_part(year, day, part, solution, input))
+        .collect()
+}
+
+/// Prints the answers as text, exiting with an error when a part failed.
+pub fn print_answers(year: u32, day: u32, solution: &dyn Solution, input: &str) {
+    let outcomes = solve(year, day, solution, input);
+    print!("{}", output::render(Format::Text, &outcomes));
+
+    if outcomes.iter().any(|outcome| outcome.result.is_err()) {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Panics;
+
+    impl Solution for Panics {
+        fn part_1(&self, input: &str) -> PartResult {
+            Ok(input.len().into())
+        }
+
+        fn part_2(&self, input: &str) -> Option<PartResult> {
+            let numbers = input.split(',').collect::<Vec<_>>();
+            Some(Ok(numbers[3].into()))
+        }
+    }
+
+    #[test]
+    fn panics_fail_only_their_part() {
+        let outcomes = solve(2022, 1, &Panics, "1,2");
+
+        assert_eq!(outcomes[0].result, Ok(Answer::Integer(3)));
+        assert_eq!(
+            outcomes[1].result,
+            Err("panicked: index out of bounds: the len is 2 but the index is 3".to_string())
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use std::error::Error;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::{Answer, Outcome};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `Day 1 part 1: 24000`, multi-line answers go indented below.
+    Text,
+    /// One JSON object per part.
+    JsonLines,
+    /// Test Anything Protocol, every part is a test that fails when the part does.
+    Tap,
+    /// One row per day with both answers and the time each took, errors listed below.
+    Table,
+}
+
+impl FromStr for Format {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::JsonLines),
+            "tap" => Ok(Format::Tap),
+            "table" => Ok(Format::Table),
+            _ => Err(format!("Unknown format {s:?}").into()),
+        }
+    }
+}
+
+fn text(outcome: &Outcome) -> String {
+    let label = format!("{} day {} part {}", outcome.year, outcome.day, outcome.part);
+
+    match &outcome.result {
+        Ok(Answer::Lines(lines)) => {
+            let lines = lines.iter().map(|line| format!("\n  {line}"));
+            format!("{label}:{}", lines.collect::<String>())
+        }
+        Ok(answer) => format!("{label}: {answer}"),
+        Err(err) => format!("{label}: error: {err}"),
+    }
+}
+
+fn json_line(outcome: &Outcome) -> String {
+    let mut line = json!({
+        "year": outcome.year,
+        "day": outcome.day,
+        "part": outcome.part,
+        "micros": outcome.duration.as_micros() as u64,
+    });
+
+    let (key, value) = match &outcome.result {
+        // JSON numbers stop at 64 bits, wider ones are written as strings
+        Ok(Answer::Integer(n)) => (
+            "answer",
+            serde_json::to_value(n).unwrap_or_else(|_| json!(n.to_string())),
+        ),
+        Ok(Answer::Text(text)) => ("answer", json!(text)),
+        Ok(Answer::Lines(lines)) => ("answer", json!(lines)),
+        Err(err) => ("error", json!(err)),
+    };
+
+    if let Value::Object(fields) = &mut line {
+        fields.insert(key.to_string(), value);
+    }
+
+    line.to_string()
+}
+
+fn tap(outcomes: &[Outcome]) -> String {
+    let mut tap = String::new();
+
+    writeln!(tap, "TAP version 13").unwrap();
+    writeln!(tap, "1..{}", outcomes.len()).unwrap();
+
+    for (index, outcome) in outcomes.iter().enumerate() {
+        let label = format!(
+            "{} - {} day {} part {}",
+            index + 1,
+            outcome.year,
+            outcome.day,
+            outcome.part
+        );
+
+        match &outcome.result {
+            Ok(Answer::Lines(lines)) => {
+                writeln!(tap, "ok {label}").unwrap();
+                for line in lines {
+                    writeln!(tap, "# {line}").unwrap();
+                }
+            }
+            Ok(answer) => writeln!(tap, "ok {label}: {answer}").unwrap(),
+            Err(err) => {
+                writeln!(tap, "not ok {label}").unwrap();
+                writeln!(tap, "# {err}").unwrap();
+            }
+        }
+    }
+
+    tap
+}
+
+fn table(outcomes: &[Outcome]) -> String {
+    let cell = |outcome: Option<&Outcome>| match outcome.map(|outcome| &outcome.result) {
+        None => "-".to_string(),
+        Some(Ok(Answer::Lines(lines))) => format!("{} lines", lines.len()),
+        Some(Ok(answer)) => answer.to_string(),
+        Some(Err(_)) => "error".to_string(),
+    };
+
+    let time = |outcome: Option<&Outcome>| match outcome {
+        Some(outcome) => format!("{:.1?}", outcome.duration),
+        None => String::new(),
+    };
+
+    let mut rows = vec![["Year", "Day", "Part 1", "Time", "Part 2", "Time"].map(String::from)];
+    let mut total = Duration::ZERO;
+
+    let mut days = vec![];
+    for outcome in outcomes {
+        if !days.contains(&(outcome.year, outcome.day)) {
+            days.push((outcome.year, outcome.day));
+        }
+    }
+
+    for (year, day) in days {
+        let parts = outcomes
+            .iter()
+            .filter(|o| (o.year, o.day) == (year, day))
+            .collect::<Vec<_>>();
+        let part = |part| parts.iter().copied().find(|o| o.part == part);
+        total += parts.iter().map(|o| o.duration).sum::<Duration>();
+
+        rows.push([
+            year.to_string(),
+            day.to_string(),
+            cell(part(1)),
+            time(part(1)),
+            cell(part(2)),
+            time(part(2)),
+        ]);
+    }
+
+    // Time spent in the parts, solving days at once makes it longer than the run
+    rows.push(["CPU total", "", "", "", "", &format!("{total:.1?}")].map(String::from));
+
+    let widths = (0..6)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = String::new();
+    for row in &rows {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>();
+        writeln!(table, "{}", cells.join("  ").trim_end()).unwrap();
+    }
+
+    for outcome in outcomes.iter().filter(|o| o.result.is_err()) {
+        writeln!(table, "{}", text(outcome)).unwrap();
+    }
+
+    table
+}
+
+/// Every outcome in `format`, ending with a new line.
+pub fn render(format: Format, outcomes: &[Outcome]) -> String {
+    match format {
+        Format::Text => outcomes.iter().map(|o| text(o) + "\n").collect(),
+        Format::JsonLines => outcomes.iter().map(|o| json_line(o) + "\n").collect(),
+        Format::Tap => tap(outcomes),
+        Format::Table => table(outcomes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcomes() -> Vec<Outcome> {
+        let outcome = |part, result| Outcome {
+            year: 2022,
+            day: 5,
+            part,
+            result,
+            duration: Duration::from_micros(42),
+        };
+
+        vec![
+            outcome(1, Ok(Answer::from("CMZ"))),
+            outcome(1, Ok(Answer::from(24000))),
+            outcome(
+                2,
+                Ok(Answer::from(vec!["#..".to_string(), ".#.".to_string()])),
+            ),
+            outcome(2, Err("line 3: invalid crate".to_string())),
+        ]
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            render(Format::Text, &outcomes()),
+            "2022 day 5 part 1: CMZ\n\
+             2022 day 5 part 1: 24000\n\
+             2022 day 5 part 2:\n  #..\n  .#.\n\
+             2022 day 5 part 2: error: line 3: invalid crate\n"
+        );
+    }
+
+    #[test]
+    fn json_lines() {
+        let lines = render(Format::JsonLines, &outcomes());
+        let lines = lines.lines().collect::<Vec<_>>();
+
+        assert_eq!(
+            lines[0],
+            r#"{"answer":"CMZ","day":5,"micros":42,"part":1,"year":2022}"#
+        );
+        assert_eq!(
+            lines[1],
+            r#"{"answer":24000,"day":5,"micros":42,"part":1,"year":2022}"#
+        );
+        assert_eq!(
+            lines[2],
+            r##"{"answer":["#..",".#."],"day":5,"micros":42,"part":2,"year":2022}"##
+        );
+        assert_eq!(
+            lines[3],
+            r#"{"day":5,"error":"line 3: invalid crate","micros":42,"part":2,"year":2022}"#
+        );
+    }
+
+    #[test]
+    fn wide_integers() {
+        let outcome = |answer: Answer| Outcome {
+            year: 2022,
+            day: 1,
+            part: 1,
+            result: Ok(answer),
+            duration: Duration::from_micros(42),
+        };
+        let outcomes = [
+            outcome(u64::MAX.into()),
+            outcome(Answer::Integer(-(1 << 70))),
+        ];
+
+        assert_eq!(
+            render(Format::Text, &outcomes),
+            "2022 day 1 part 1: 18446744073709551615
+2022 day 1 part 1: -1180591620717411303424
+"
+        );
+        assert_eq!(
+            render(Format::JsonLines, &outcomes),
+            "{\"answer\":18446744073709551615,\"day\":1,\"micros\":42,\"part\":1,\"year\":2022}\n\
+             {\"answer\":\"-1180591620717411303424\",\"day\":1,\"micros\":42,\"part\":1,\"year\":2022}\n"
+        );
+    }
+
+    #[test]
+    fn tap() {
+        assert_eq!(
+            render(Format::Tap, &outcomes()),
+            "TAP version 13\n\
+             1..4\n\
+             ok 1 - 2022 day 5 part 1: CMZ\n\
+             ok 2 - 2022 day 5 part 1: 24000\n\
+             ok 3 - 2022 day 5 part 2\n# #..\n# .#.\n\
+             not ok 4 - 2022 day 5 part 2\n# line 3: invalid crate\n"
+        );
+    }
+    #[test]
+    fn table() {
+        let mut outcomes = outcomes();
+        // Day 6 comes first
+        outcomes[0].day = 6;
+        outcomes[2].day = 6;
+
+        assert_eq!(
+            render(Format::Table, &outcomes),
+            "Year       Day  Part 1  Time    Part 2   Time\n\
+             2022       6    CMZ     42.0µs  2 lines  42.0µs\n\
+             2022       5    24000   42.0µs  error    42.0µs\n\
+             CPU total                                168.0µs\n\
+             2022 day 5 part 2: error: line 3: invalid crate\n"
+        );
+    }
+}
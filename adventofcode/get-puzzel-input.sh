@@ -1,9 +1,40 @@
 #!/usr/bin/env bash
+# Saves the puzzle input of a day next to its crate, in <year>/day-NN/input.txt.
+# The old form, <day> [year], still prints the input to stdout, of 2022 when no year is given.
 
-day="$1"
-year="${2:-2022}"
+usage="Usage: $0 <year> <day>, or $0 <day> [year] to print it"
 
-curl "https://adventofcode.com/$year/day/$day/input" -s \
-  -H 'accept: text/html' \
-  -H "cookie: session=$AOC_COOKIE;" \
-  --compressed
+if [ $# -lt 1 ] || [ $# -gt 2 ]; then
+  echo "$usage" >&2
+  exit 1
+fi
+
+fetch() {
+  curl "https://adventofcode.com/$1/day/$2/input" -s -f \
+    -H 'accept: text/html' \
+    -H "cookie: session=$AOC_COOKIE;" \
+    --compressed \
+    "${@:3}"
+}
+
+# Days go from 1 to 25, years from 2015: a first argument up to 25 is a day
+if [ "$1" -le 25 ] 2>/dev/null; then
+  fetch "${2:-2022}" "$1"
+  exit
+fi
+
+if [ $# -ne 2 ]; then
+  echo "$usage" >&2
+  exit 1
+fi
+
+year="$1"
+day="$2"
+dir="$(dirname "$0")/$year/$(printf 'day-%02d' "$day")"
+
+if [ ! -d "$dir" ]; then
+  echo "$dir doesn't exist, create it with: aoc new $year $day" >&2
+  exit 1
+fi
+
+fetch "$year" "$day" -o "$dir/input.txt"
@@ -0,0 +1,179 @@
+use std::error::Error;
+
+use aoc::Solution;
+
+/// A solved day and the directory of its crate, where its inputs are.
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub solution: Box<dyn Solution>,
+    pub dir: &'static str,
+}
+
+/// Every solved day, in order.
+pub fn all() -> Vec<Day> {
+    let day = |year, day, solution: Box<dyn Solution>, dir| Day {
+        year,
+        day,
+        solution,
+        dir,
+    };
+
+    vec![
+        day(
+            2022,
+            1,
+            Box::new(day_01::Day01),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../2022/day-01"),
+        ),
+        day(
+            2022,
+            2,
+            Box::<day_02::Day02>::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../2022/day-02"),
+        ),
+        day(
+            2022,
+            3,
+            Box::<day_03::Day03>::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../2022/day-03"),
+        ),
+        day(
+            2022,
+            4,
+            Box::<day_04::Day04>::default(),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../2022/day-04"),
+        ),
+        day(
+            2022,
+            5,
+            Box::new(day_05::Day05),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../2022/day-05"),
+        ),
+        day(
+            2022,
+            6,
+            Box::new(day_06::Day06),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../2022/day-06"),
+        ),
+        day(
+            2022,
+            7,
+            Box::new(day_07::Day07),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../2022/day-07"),
+        ),
+        day(
+            2022,
+            8,
+            Box::new(day_08::Day08),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../2022/day-08"),
+        ),
+    ]
+}
+
+/// The days to run: every day of `years`, or only `days` of a single year. Everything when both
+/// are empty.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    pub years: Vec<u32>,
+    pub days: Vec<u32>,
+}
+
+impl Selection {
+    /// Numbers from 2015 on are years, the others days.
+    pub fn parse(numbers: &[String]) -> Result<Self, Box<dyn Error>> {
+        let mut selection = Selection::default();
+
+        for number in numbers {
+            let number = number
+                .parse::<u32>()
+                .map_err(|_| format!("{number:?} is neither a year nor a day"))?;
+
+            match number {
+                1..=25 => selection.days.push(number),
+                2015.. => selection.years.push(number),
+                _ => return Err(format!("{number} is neither a year nor a day").into()),
+            }
+        }
+
+        if !selection.days.is_empty() && selection.years.len() > 1 {
+            return Err("Days can only be picked from a single year".into());
+        }
+
+        Ok(selection)
+    }
+
+    /// Every day of the selection, days without a year are from the last year with a solved day.
+    pub fn select<'a>(&self, all: &'a [Day]) -> Result<Vec<&'a Day>, Box<dyn Error>> {
+        let last = all.iter().map(|day| day.year).max();
+        let years = match (self.years.as_slice(), last) {
+            ([], Some(last)) if !self.days.is_empty() => vec![last],
+            (years, _) => years.to_vec(),
+        };
+
+        if let Some(year) = years.iter().find(|&&y| !all.iter().any(|d| d.year == y)) {
+            return Err(format!("No day of {year} is solved yet").into());
+        }
+        for day in &self.days {
+            if !all.iter().any(|d| years.contains(&d.year) && d.day == *day) {
+                return Err(format!("{} day {day} is not solved yet", years[0]).into());
+            }
+        }
+
+        Ok(all
+            .iter()
+            .filter(|d| years.is_empty() || years.contains(&d.year))
+            .filter(|d| self.days.is_empty() || self.days.contains(&d.day))
+            .collect())
+    }
+
+    /// Whole years or everything, rather than some days.
+    pub fn is_whole_years(&self) -> bool {
+        self.days.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selection(numbers: &str) -> Result<Selection, Box<dyn Error>> {
+        let numbers = numbers
+            .split_whitespace()
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        Selection::parse(&numbers)
+    }
+
+    fn selected(numbers: &str, all: &[Day]) -> Vec<(u32, u32)> {
+        selection(numbers)
+            .unwrap()
+            .select(all)
+            .unwrap()
+            .iter()
+            .map(|day| (day.year, day.day))
+            .collect()
+    }
+
+    #[test]
+    fn years_and_days() {
+        let day = |year, day| Day {
+            year,
+            day,
+            solution: Box::new(day_01::Day01),
+            dir: "",
+        };
+        let all = vec![day(2021, 1), day(2021, 2), day(2022, 1), day(2022, 3)];
+
+        assert_eq!(selected("", &all).len(), 4);
+        assert_eq!(selected("2021", &all), vec![(2021, 1), (2021, 2)]);
+        assert_eq!(selected("3 1", &all), vec![(2022, 1), (2022, 3)]);
+        assert_eq!(selected("2021 2", &all), vec![(2021, 2)]);
+
+        assert!(selection("2021 2022 1").is_err());
+        assert!(selection("26").is_err());
+        assert!(selection("2023").unwrap().select(&all).is_err());
+        assert!(selection("2").unwrap().select(&all).is_err());
+    }
+}
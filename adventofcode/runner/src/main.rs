@@ -0,0 +1,322 @@
+mod days;
+mod parallel;
+mod scaffold;
+
+use std::env;
+use std::error::Error;
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::examples::Example;
+use aoc::input::Source;
+use aoc::output::{self, Format};
+use aoc::Outcome;
+
+use days::{Day, Selection};
+
+const USAGE: &str = "Usage: aoc run [YEAR]... [DAY]... | --all [--input <path>|-] \
+    [--example <name>] [--format text|json|tap|table] [--jobs N] [--budget <time>] \
+    | aoc list [YEAR] | aoc new <year> <day>";
+
+enum Command {
+    Run {
+        selection: Selection,
+        source: Source,
+        /// A table when running whole years unless told otherwise.
+        format: Option<Format>,
+        /// How many parts are solved at once, the two parts of a day are separate jobs.
+        jobs: usize,
+        /// Fails the run when it takes longer, from the first day started to the last finished.
+        budget: Option<Duration>,
+    },
+    /// Solved days of every year, or of one.
+    List { year: Option<u32> },
+    /// Create the crate of a day and register it here.
+    New { year: u32, day: u32 },
+}
+
+fn parse_args(args: Vec<String>) -> Result<Command, Box<dyn Error>> {
+    let (source, args) = Source::extract(args)?;
+    let (cmd, args) = args.split_first().ok_or(USAGE)?;
+
+    match (cmd.as_str(), args) {
+        ("run", _) => {}
+        ("list", []) if source == Source::Cached => return Ok(Command::List { year: None }),
+        ("list", [year]) if source == Source::Cached => {
+            return Ok(Command::List {
+                year: Some(year.parse()?),
+            })
+        }
+        ("new", [year, day]) if source == Source::Cached => {
+            return Ok(Command::New {
+                year: year.parse()?,
+                day: day.parse()?,
+            })
+        }
+        _ => return Err(USAGE.into()),
+    }
+
+    let mut numbers = vec![];
+    let mut all = false;
+    let mut format = None;
+    let mut jobs = thread::available_parallelism().map_or(1, |jobs| jobs.get());
+    let mut budget = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--format" => format = Some(args.next().ok_or(USAGE)?.parse()?),
+            "--jobs" => jobs = args.next().ok_or(USAGE)?.parse()?,
+            "--budget" => budget = Some(parse_duration(args.next().ok_or(USAGE)?)?),
+            number => numbers.push(number.to_string()),
+        }
+    }
+
+    if all && !numbers.is_empty() {
+        return Err("--all runs every day, it can't be given years or days".into());
+    }
+
+    Ok(Command::Run {
+        selection: Selection::parse(&numbers)?,
+        source,
+        format,
+        jobs,
+        budget,
+    })
+}
+
+/// `1.5s`, `200ms` or `500us`.
+fn parse_duration(s: &str) -> Result<Duration, Box<dyn Error>> {
+    let (number, unit) = s
+        .find(|c: char| c.is_alphabetic() || c == 'µ')
+        .map(|index| s.split_at(index))
+        .ok_or_else(|| format!("{s:?} has no unit, like 1s or 200ms"))?;
+    let number = number.parse::<f64>()?;
+
+    let seconds = match unit {
+        "s" => number,
+        "ms" => number / 1e3,
+        "us" | "µs" => number / 1e6,
+        _ => return Err(format!("Unknown unit {unit:?}, use s, ms or us").into()),
+    };
+
+    Ok(Duration::try_from_secs_f64(seconds)?)
+}
+
+/// Marks the parts of `day` whose answer to `example` isn't the expected one as failed.
+fn check_example(day: &Day, example: &Example, mut outcomes: Vec<Outcome>) -> Vec<Outcome> {
+    for mismatch in example.check(&outcomes) {
+        let failure = Outcome {
+            year: day.year,
+            day: day.day,
+            part: mismatch.part,
+            result: Err(mismatch.to_string()),
+            duration: Duration::ZERO,
+        };
+
+        match outcomes.iter_mut().find(|o| o.part == mismatch.part) {
+            Some(outcome) => outcome.result = failure.result,
+            None => outcomes.push(failure),
+        }
+    }
+
+    outcomes
+}
+
+/// What a day gets run on.
+enum DayInput {
+    Input(String),
+    Example(Example),
+    /// Why the day is left out.
+    Skipped(String),
+}
+
+impl DayInput {
+    fn text(&self) -> Option<&str> {
+        match self {
+            DayInput::Input(input) => Some(input),
+            DayInput::Example(example) => Some(&example.input),
+            DayInput::Skipped(_) => None,
+        }
+    }
+}
+
+fn day_input(day: &Day, selection: &Selection, source: &Source) -> Result<DayInput, String> {
+    let dir = Path::new(day.dir);
+
+    if let Source::Example(name) = source {
+        let example = Example::load(dir, name).map_err(|err| err.to_string())?;
+        return Ok(DayInput::Example(example));
+    }
+
+    match source.read(dir) {
+        Ok(input) => Ok(DayInput::Input(input)),
+        // Running everything goes on without the days whose input isn't fetched yet
+        Err(err) if selection.is_whole_years() && *source == Source::Cached => {
+            Ok(DayInput::Skipped(err.to_string()))
+        }
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+fn run(
+    selection: &Selection,
+    source: &Source,
+    format: Format,
+    jobs: usize,
+    budget: Option<Duration>,
+) -> Result<(), Box<dyn Error>> {
+    let all = days::all();
+    let selected = selection.select(&all)?;
+
+    if matches!(source, Source::Stdin | Source::Path(_)) && selected.len() > 1 {
+        return Err("--input needs a single day, each day has its own puzzle input".into());
+    }
+
+    let start = Instant::now();
+    let inputs = selected
+        .iter()
+        .map(|day| day_input(day, selection, source))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Every part is a job of its own, in the order of the days
+    let parts = selected
+        .iter()
+        .zip(&inputs)
+        .filter_map(|(day, input)| Some((*day, input.text()?)))
+        .flat_map(|(day, input)| [1, 2].map(|part| (day, part, input)))
+        .collect::<Vec<_>>();
+    let mut solved = parallel::map(&parts, jobs, |(day, part, input)| {
+        aoc::solve_part(day.year, day.day, *part, day.solution.as_ref(), input)
+    })
+    .into_iter()
+    .flatten()
+    .peekable();
+    let elapsed = start.elapsed();
+
+    let mut outcomes = vec![];
+    let mut skipped = 0;
+    for (day, input) in selected.iter().zip(&inputs) {
+        let mut parts = vec![];
+        while let Some(outcome) = solved.next_if(|o| (o.year, o.day) == (day.year, day.day)) {
+            parts.push(outcome);
+        }
+
+        match input {
+            DayInput::Input(_) => outcomes.extend(parts),
+            DayInput::Example(example) => outcomes.extend(check_example(day, example, parts)),
+            DayInput::Skipped(err) => {
+                eprintln!("{} day {} skipped: {err}", day.year, day.day);
+                skipped += 1;
+            }
+        }
+    }
+
+    print!("{}", output::render(format, &outcomes));
+
+    let failed = outcomes.iter().any(|outcome| outcome.result.is_err());
+
+    if skipped > 0 {
+        eprintln!("Skipped {skipped} of {} days", selected.len());
+    }
+    // A budget is only met by running every day
+    let incomplete = skipped > 0 && budget.is_some();
+
+    let over_budget = budget.filter(|budget| elapsed > *budget);
+    if let Some(budget) = over_budget {
+        eprintln!("Took {elapsed:.1?}, over the budget of {budget:.1?}");
+    }
+
+    if failed || incomplete || over_budget.is_some() {
+        process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn list(year: Option<u32>) -> Result<(), Box<dyn Error>> {
+    let all = days::all();
+
+    let mut years = all.iter().map(|day| day.year).collect::<Vec<_>>();
+    years.dedup();
+
+    if let Some(year) = year.filter(|year| !years.contains(year)) {
+        return Err(format!("No day of {year} is solved yet").into());
+    }
+
+    for year in years
+        .into_iter()
+        .filter(|y| year.is_none_or(|year| year == *y))
+    {
+        let days = all
+            .iter()
+            .filter(|day| day.year == year)
+            .map(|day| day.day.to_string())
+            .collect::<Vec<_>>();
+
+        println!("{year}: {}", days.join(" "));
+    }
+
+    Ok(())
+}
+
+fn new(year: u32, day: u32) -> Result<(), Box<dyn Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .canonicalize()?;
+    let dir = scaffold::new_day(&root, year, day)?;
+
+    println!("Created {}", dir.display());
+
+    Ok(())
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match parse_args(args) {
+        Ok(Command::Run {
+            selection,
+            source,
+            format,
+            jobs,
+            budget,
+        }) => {
+            let format = format.unwrap_or(match selection.is_whole_years() {
+                true => Format::Table,
+                false => Format::Text,
+            });
+
+            run(&selection, &source, format, jobs, budget)
+        }
+        Ok(Command::List { year }) => list(year),
+        Ok(Command::New { year, day }) => new(year, day),
+        Err(err) => Err(err),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budgets() {
+        assert_eq!(parse_duration("2s").unwrap(), Duration::from_secs(2));
+        assert_eq!(parse_duration("1.5s").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse_duration("250ms").unwrap(), Duration::from_millis(250));
+        assert_eq!(parse_duration("40us").unwrap(), Duration::from_micros(40));
+        assert_eq!(parse_duration("40µs").unwrap(), Duration::from_micros(40));
+
+        assert!(parse_duration("2").is_err());
+        assert!(parse_duration("2h").is_err());
+        assert!(parse_duration("-1s").is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Calls `f` on every item from `jobs` threads, each taking the next item once it's done with
+/// one. The results are in the order of `items`.
+pub fn map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(items.iter().map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+
+                let result = f(item);
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item is taken by a thread"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn keeps_the_order_of_the_items() {
+        let items = (0..20u64).collect::<Vec<_>>();
+
+        let squares = map(&items, 4, |n| {
+            // The first items finish last
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        });
+
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        assert_eq!(map(&items, 0, |n| n + 1)[19], 20);
+        assert!(map(&[] as &[u64], 4, |n| *n).is_empty());
+    }
+}
@@ -0,0 +1,321 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Crate of a day, relative to the `adventofcode` directory.
+fn day_dir(year: u32, day: u32) -> PathBuf {
+    PathBuf::from(year.to_string()).join(format!("day-{day:02}"))
+}
+
+/// Name of the crate of a day. The runner can't depend on two crates with the same name, the 2022
+/// days came first and keep `day-NN`, other years get their own suffix.
+fn crate_name(year: u32, day: u32) -> String {
+    match year {
+        2022 => format!("day-{day:02}"),
+        _ => format!("day-{day:02}-{year}"),
+    }
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc = {{ path = "../../aoc" }}
+"#
+    )
+}
+
+fn lib_rs(year: u32, day: u32) -> String {
+    format!(
+        r#"use aoc::{{PartResult, Solution}};
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    fn part_1(&self, _input: &str) -> PartResult {{
+        Err("not solved yet".into())
+    }}
+
+    fn part_2(&self, _input: &str) -> Option<PartResult> {{
+        None
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    aoc::test_examples!({year}, {day}, Day{day:02});
+}}
+"#
+    )
+}
+
+fn main_rs(name: &str, year: u32, day: u32) -> String {
+    let lib = name.replace('-', "_");
+
+    format!(
+        r#"use {lib}::Day{day:02};
+
+const USAGE: &str = "Usage: {name} [--input <path>|-] [--example <name>]";
+
+fn main() {{
+    let (input, args) = aoc::input::from_args(env!("CARGO_MANIFEST_DIR")).unwrap();
+    assert!(args.is_empty(), "{{USAGE}}");
+
+    aoc::print_answers({year}, {day}, &Day{day:02}, &input);
+}}
+"#
+    )
+}
+
+fn readme(year: u32, day: u32) -> String {
+    format!(
+        r#"# Day {day}
+
+[Puzzle](https://adventofcode.com/{year}/day/{day})
+
+Put the example of the puzzle in `examples/example.txt` and its answers in
+`examples/example.part1` and `examples/example.part2`, `cargo test` checks them once they are
+there.
+
+Fetch the puzzle input with `../../get-puzzel-input.sh {year} {day}`, then `cargo run` or
+`aoc run {year} {day}`.
+"#
+    )
+}
+
+/// `toml` with `dependency` added as the last line of its `[dependencies]` table.
+fn add_dependency(toml: &str, dependency: &str) -> Result<String, Box<dyn Error>> {
+    let mut lines = toml.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "[dependencies]")
+        .ok_or("runner/Cargo.toml has no [dependencies]")?;
+
+    // The table goes on until the next one, blank lines before that one stay after it
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| line.trim_start().starts_with('['))
+        .map_or(lines.len(), |index| start + 1 + index);
+    let last = lines[start..end]
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(start, |index| start + index);
+
+    lines.insert(last + 1, dependency);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the crate to the dependencies of the runner, and the day to `days::all`.
+fn register(root: &Path, year: u32, day: u32) -> Result<(), Box<dyn Error>> {
+    let name = crate_name(year, day);
+
+    let manifest = root.join("runner/Cargo.toml");
+    let dependency = format!(
+        "{name} = {{ path = \"../{}\" }}",
+        day_dir(year, day).display()
+    );
+    let toml = add_dependency(&fs::read_to_string(&manifest)?, &dependency)?;
+
+    let days = root.join("runner/src/days.rs");
+    let source = fs::read_to_string(&days)?;
+    let entry = format!(
+        r#"        day(
+            {year},
+            {day},
+            Box::new({}::Day{day:02}),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../{}"),
+        ),
+"#,
+        name.replace('-', "_"),
+        day_dir(year, day).display()
+    );
+
+    // Before the first day that comes after, or at the end of the list
+    let position = source
+        .match_indices("        day(\n")
+        .find(|(index, start)| {
+            let mut numbers = source[index + start.len()..]
+                .split(',')
+                .take(2)
+                .map(|number| number.trim().parse::<u32>());
+
+            match (numbers.next(), numbers.next()) {
+                (Some(Ok(y)), Some(Ok(d))) => (y, d) > (year, day),
+                _ => false,
+            }
+        })
+        .map(|(index, _)| index)
+        .or_else(|| source.rfind("    ]\n"))
+        .ok_or("days.rs has no list of days")?;
+
+    let mut source = source;
+    source.insert_str(position, &entry);
+
+    fs::write(manifest, toml)?;
+    fs::write(days, source)?;
+
+    Ok(())
+}
+
+/// Creates the crate of a new day under `root`, the `adventofcode` directory, and registers it
+/// with the runner. Returns the directory of the crate.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<PathBuf, Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("There is no day {day}, days go from 1 to 25").into());
+    }
+    if year < 2015 {
+        return Err(format!("There is no Advent of Code {year}, the first was in 2015").into());
+    }
+
+    let dir = root.join(day_dir(year, day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    let created = write_crate(&dir, year, day).and_then(|()| register(root, year, day));
+    if let Err(err) = created {
+        // Don't leave a day behind that the runner doesn't know about
+        let _ = fs::remove_dir_all(&dir);
+        return Err(err);
+    }
+
+    Ok(dir)
+}
+
+fn write_crate(dir: &Path, year: u32, day: u32) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(dir.join("src"))?;
+    fs::create_dir_all(dir.join("examples"))?;
+
+    let name = crate_name(year, day);
+    fs::write(dir.join("Cargo.toml"), cargo_toml(&name))?;
+    fs::write(dir.join("src/lib.rs"), lib_rs(year, day))?;
+    fs::write(dir.join("src/main.rs"), main_rs(&name, year, day))?;
+    fs::write(dir.join("readme.md"), readme(year, day))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    /// A copy of the runner's manifest and list of days in a directory of its own.
+    fn root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("runner/src")).unwrap();
+
+        let runner = Path::new(env!("CARGO_MANIFEST_DIR"));
+        fs::copy(runner.join("Cargo.toml"), root.join("runner/Cargo.toml")).unwrap();
+        fs::copy(runner.join("src/days.rs"), root.join("runner/src/days.rs")).unwrap();
+
+        root
+    }
+
+    #[test]
+    fn creates_and_registers_a_day() {
+        let root = root("new");
+        let dir = new_day(&root, 2022, 9).unwrap();
+
+        let toml = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(toml.contains("name = \"day-09\""));
+        assert!(fs::read_to_string(dir.join("src/lib.rs"))
+            .unwrap()
+            .contains("pub struct Day09;"));
+        // Left for the example of the puzzle, `cargo test` skips the day until then
+        assert!(dir.join("examples").is_dir());
+        assert!(!dir.join("examples/example.txt").exists());
+
+        let runner = fs::read_to_string(root.join("runner/Cargo.toml")).unwrap();
+        assert!(runner.contains(
+            "day-08 = { path = \"../2022/day-08\" }\nday-09 = { path = \"../2022/day-09\" }\n"
+        ));
+
+        let days = fs::read_to_string(root.join("runner/src/days.rs")).unwrap();
+        let nine = days.find("Box::new(day_09::Day09)").unwrap();
+        assert!(days.find("day_08::Day08").unwrap() < nine);
+        assert!(days[nine..].starts_with("Box::new(day_09::Day09),\n            concat!"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn other_years_get_their_own_crate_names() {
+        let root = root("years");
+        new_day(&root, 2023, 1).unwrap();
+        let dir = new_day(&root, 2021, 25).unwrap();
+
+        let toml = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(toml.contains("name = \"day-25-2021\""));
+        assert!(fs::read_to_string(dir.join("src/main.rs"))
+            .unwrap()
+            .contains("aoc::print_answers(2021, 25, &Day25, &input);"));
+
+        let runner = fs::read_to_string(root.join("runner/Cargo.toml")).unwrap();
+        assert!(runner.contains("day-01-2023 = { path = \"../2023/day-01\" }\n"));
+
+        // Sorted by year, then day
+        let days = fs::read_to_string(root.join("runner/src/days.rs")).unwrap();
+        let position = |solution: &str| days.find(solution).unwrap();
+        assert!(position("day_25_2021::Day25") < position("day_01::Day01"));
+        assert!(position("day_08::Day08") < position("day_01_2023::Day01"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn dependencies_go_in_their_table() {
+        let toml = "[package]\nname = \"runner\"\n\n[dependencies]\naoc = { path = \"../aoc\" }\n\n[dev-dependencies]\nproptest = \"1\"\n";
+
+        assert_eq!(
+            add_dependency(toml, "day-01 = { path = \"../2022/day-01\" }").unwrap(),
+            "[package]\nname = \"runner\"\n\n[dependencies]\naoc = { path = \"../aoc\" }\nday-01 = { path = \"../2022/day-01\" }\n\n[dev-dependencies]\nproptest = \"1\"\n"
+        );
+        assert_eq!(
+            add_dependency("[dependencies]", "aoc = \"1\"").unwrap(),
+            "[dependencies]\naoc = \"1\"\n"
+        );
+        assert!(add_dependency("[package]\n", "aoc = \"1\"").is_err());
+    }
+
+    #[test]
+    fn failed_registration_removes_the_day() {
+        let root = root("unregistered");
+        fs::write(
+            root.join("runner/Cargo.toml"),
+            "[package]\nname = \"runner\"\n",
+        )
+        .unwrap();
+
+        assert!(new_day(&root, 2022, 9).is_err());
+        assert!(!root.join("2022/day-09").exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn refuses_to_overwrite() {
+        let root = root("overwrite");
+        fs::create_dir_all(root.join("2022/day-05")).unwrap();
+
+        let err = new_day(&root, 2022, 5).unwrap_err();
+        assert!(err.to_string().ends_with("day-05 already exists"));
+        assert!(new_day(&root, 2022, 26).is_err());
+
+        let days = fs::read_to_string(root.join("runner/src/days.rs")).unwrap();
+        assert_eq!(days.matches("day_05::Day05").count(), 1);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}